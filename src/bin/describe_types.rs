@@ -4,15 +4,27 @@
 //   file, You can obtain one at http://mozilla.org/MPL/2.0/.
 //
 
-use std::{error::Error, fmt::Display};
+use std::{
+    error::Error,
+    fmt::Display,
+    io::{stdout, IsTerminal},
+};
 
 use clap::{Parser, ValueEnum};
-use type_description::{render::render_to_markdown, render::render_to_terminal, TypeDescription};
+use type_description::{
+    render::render_to_html_page, render::render_to_json_schema, render::render_to_markdown,
+    render::render_to_terminal_with, render::render_to_toml, render::render_to_yaml,
+    render::TerminalOptions, TypeDescription,
+};
 
 #[derive(Debug, ValueEnum, PartialEq, Clone, Copy)]
 enum OutputFormat {
     Markdown,
     Terminal,
+    JsonSchema,
+    Toml,
+    Yaml,
+    Html,
 }
 
 impl Display for OutputFormat {
@@ -20,6 +32,41 @@ impl Display for OutputFormat {
         match self {
             OutputFormat::Markdown => write!(f, "markdown"),
             OutputFormat::Terminal => write!(f, "terminal"),
+            OutputFormat::JsonSchema => write!(f, "json-schema"),
+            OutputFormat::Toml => write!(f, "toml"),
+            OutputFormat::Yaml => write!(f, "yaml"),
+            OutputFormat::Html => write!(f, "html"),
+        }
+    }
+}
+
+#[derive(Debug, ValueEnum, PartialEq, Clone, Copy)]
+enum ColorChoice {
+    /// Use colors if writing to a terminal and `NO_COLOR` is not set
+    Auto,
+    Always,
+    Never,
+}
+
+impl Display for ColorChoice {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ColorChoice::Auto => write!(f, "auto"),
+            ColorChoice::Always => write!(f, "always"),
+            ColorChoice::Never => write!(f, "never"),
+        }
+    }
+}
+
+impl ColorChoice {
+    fn use_colors(self) -> bool {
+        match self {
+            ColorChoice::Auto => {
+                std::env::var_os("NO_COLOR").map_or(true, |value| value.is_empty())
+                    && stdout().is_terminal()
+            }
+            ColorChoice::Always => true,
+            ColorChoice::Never => false,
         }
     }
 }
@@ -29,6 +76,14 @@ impl Display for OutputFormat {
 struct Args {
     #[clap(short, long, value_parser)]
     output_format: OutputFormat,
+
+    /// When to use colors in the terminal output
+    #[clap(long, value_parser, default_value_t = ColorChoice::Auto)]
+    color: ColorChoice,
+
+    /// Use colors readable on a light background in the terminal output
+    #[clap(long)]
+    light: bool,
 }
 
 fn main() -> Result<(), Box<dyn Error>> {
@@ -44,12 +99,33 @@ fn main() -> Result<(), Box<dyn Error>> {
             let terminal_width = term_size::dimensions().map(|(w, _)| w).unwrap_or(80);
             let arena = pretty::Arena::new();
 
-            let rendered_doc = render_to_terminal(&input, &arena);
+            let options = if args.light {
+                TerminalOptions::light()
+            } else {
+                TerminalOptions::default()
+            }
+            .with_colors(args.color.use_colors());
+            let rendered_doc = render_to_terminal_with(&input, &arena, &options);
 
             let mut output = String::new();
             rendered_doc.render_fmt(terminal_width, &mut output)?;
             println!("{}", output);
         }
+        OutputFormat::JsonSchema => {
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&render_to_json_schema(&input))?
+            );
+        }
+        OutputFormat::Toml => {
+            print!("{}", render_to_toml(&input)?);
+        }
+        OutputFormat::Yaml => {
+            print!("{}", render_to_yaml(&input)?);
+        }
+        OutputFormat::Html => {
+            print!("{}", render_to_html_page(&input)?);
+        }
     }
 
     Ok(())
@@ -0,0 +1,212 @@
+//
+//   This Source Code Form is subject to the terms of the Mozilla Public
+//   License, v. 2.0. If a copy of the MPL was not distributed with this
+//   file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+use std::borrow::Cow;
+
+use crate::{
+    BytesEncoding, Constraints, EnumVariant, EnumVariantRepresentation, StructField,
+    TypeDescription, TypeEnumKind, TypeKind,
+};
+
+/// Builds a [`struct@TypeDescription`] of a [`TypeKind::Struct`]
+///
+/// Created by [`TypeDescription::builder`].
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct StructBuilder {
+    desc: TypeDescription,
+    fields: Vec<StructField>,
+}
+
+impl StructBuilder {
+    /// Set the struct's documentation
+    pub fn doc(mut self, doc: impl Into<Cow<'static, str>>) -> Self {
+        self.desc.set_doc(Some(doc.into()));
+        self
+    }
+
+    /// Set the constraints on values of the struct
+    pub fn constraints(mut self, constraints: Constraints) -> Self {
+        self.desc.set_constraints(constraints);
+        self
+    }
+
+    /// Add a required field
+    pub fn field(self, name: impl Into<Cow<'static, str>>, kind: TypeDescription) -> Self {
+        self.push_field(StructField::new_owned(name, None, kind, false))
+    }
+
+    /// Add a field that may be omitted
+    pub fn optional_field(self, name: impl Into<Cow<'static, str>>, kind: TypeDescription) -> Self {
+        self.push_field(StructField::new_owned(name, None, kind, true))
+    }
+
+    /// Add a required field with documentation
+    pub fn documented_field(
+        self,
+        name: impl Into<Cow<'static, str>>,
+        doc: impl Into<Cow<'static, str>>,
+        kind: TypeDescription,
+    ) -> Self {
+        self.push_field(StructField::new_owned(name, Some(doc.into()), kind, false))
+    }
+
+    /// Add a fully specified field, e.g. one with a default value
+    pub fn push_field(mut self, field: StructField) -> Self {
+        self.fields.push(field);
+        self
+    }
+
+    /// Finish the struct
+    pub fn build(mut self) -> TypeDescription {
+        self.desc.set_kind(TypeKind::Struct(self.fields));
+        self.desc
+    }
+}
+
+/// Builds a [`struct@TypeDescription`] of a [`TypeKind::Enum`]
+///
+/// Created by [`TypeDescription::enum_builder`].
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct EnumBuilder {
+    desc: TypeDescription,
+    enum_kind: TypeEnumKind,
+    variants: Vec<EnumVariant>,
+}
+
+impl EnumBuilder {
+    /// Set the enum's documentation
+    pub fn doc(mut self, doc: impl Into<Cow<'static, str>>) -> Self {
+        self.desc.set_doc(Some(doc.into()));
+        self
+    }
+
+    /// Set how the enum is tagged, [`TypeEnumKind::External`] by default
+    pub fn tagging(mut self, enum_kind: TypeEnumKind) -> Self {
+        self.enum_kind = enum_kind;
+        self
+    }
+
+    /// Add a variant without content, represented by its name
+    pub fn unit_variant(self, name: impl Into<Cow<'static, str>>) -> Self {
+        let name = name.into();
+        let repr = EnumVariantRepresentation::String(name.clone());
+        self.push_variant(EnumVariant::new_owned(name, None, repr))
+    }
+
+    /// Add a variant with the given content
+    ///
+    /// Like the derive does for newtype variants, the content is wrapped in a type named after
+    /// the variant.
+    pub fn variant(self, name: impl Into<Cow<'static, str>>, content: TypeDescription) -> Self {
+        let name = name.into();
+        let payload =
+            TypeDescription::new_owned(name.as_ref(), TypeKind::Wrapped(Box::new(content)), None);
+        let repr = EnumVariantRepresentation::Wrapped(Box::new(payload));
+        self.push_variant(EnumVariant::new_owned(name, None, repr))
+    }
+
+    /// Add a fully specified variant, e.g. one with documentation
+    pub fn push_variant(mut self, variant: EnumVariant) -> Self {
+        self.variants.push(variant);
+        self
+    }
+
+    /// Finish the enum
+    pub fn build(mut self) -> TypeDescription {
+        self.desc
+            .set_kind(TypeKind::Enum(self.enum_kind, self.variants));
+        self.desc
+    }
+}
+
+impl TypeDescription {
+    /// Start building a description of a struct with the given name
+    ///
+    /// This is meant for manual [`AsTypeDescription`](crate::AsTypeDescription) implementations
+    /// where the derive cannot be used.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use type_description::{AsTypeDescription, TypeDescription, TypeKind};
+    ///
+    ///     let desc = TypeDescription::builder("Config")
+    ///         .doc("The server configuration")
+    ///         .field("port", u16::as_type_description())
+    ///         .optional_field("hosts", TypeDescription::array_of(String::as_type_description()))
+    ///         .build();
+    ///
+    ///     assert!(matches!(desc.kind(), TypeKind::Struct(fields) if fields.len() == 2));
+    /// ```
+    pub fn builder(name: impl Into<String>) -> StructBuilder {
+        StructBuilder {
+            desc: TypeDescription::new_owned(name, TypeKind::Struct(vec![]), None),
+            fields: vec![],
+        }
+    }
+
+    /// Start building a description of an externally tagged enum with the given name
+    pub fn enum_builder(name: impl Into<String>) -> EnumBuilder {
+        EnumBuilder {
+            desc: TypeDescription::new_owned(
+                name,
+                TypeKind::Enum(TypeEnumKind::External, vec![]),
+                None,
+            ),
+            enum_kind: TypeEnumKind::External,
+            variants: vec![],
+        }
+    }
+
+    /// Describe a sequence of bytes written in the given encoding
+    #[must_use]
+    pub fn bytes(encoding: BytesEncoding) -> Self {
+        let doc = match encoding {
+            BytesEncoding::Raw => "A sequence of bytes",
+            BytesEncoding::Base64 => "A sequence of bytes, encoded as base64",
+            BytesEncoding::Hex => "A sequence of bytes, encoded as hexadecimal digits",
+        };
+        TypeDescription::new_owned("Bytes", TypeKind::Bytes { encoding }, Some(doc.into()))
+    }
+
+    /// Describe an array of the given elements
+    #[must_use]
+    pub fn array_of(element: TypeDescription) -> Self {
+        TypeDescription::new_owned(
+            format!("Array of '{}'s", element.name()),
+            TypeKind::Array(Box::new(element)),
+            None,
+        )
+    }
+
+    /// Describe an array of exactly `length` of the given elements
+    #[must_use]
+    pub fn fixed_array_of(element: TypeDescription, length: usize) -> Self {
+        TypeDescription::new_owned(
+            format!("Array of {length} '{}'s", element.name()),
+            TypeKind::FixedArray {
+                element: Box::new(element),
+                length,
+            },
+            None,
+        )
+    }
+
+    /// Describe a map from the given keys to the given values
+    #[must_use]
+    pub fn map_of(key: TypeDescription, value: TypeDescription) -> Self {
+        TypeDescription::new_owned(
+            format!("Table of '{} => {}'", key.name(), value.name()),
+            TypeKind::HashMap {
+                key: Box::new(key),
+                value: Box::new(value),
+            },
+            None,
+        )
+    }
+}
@@ -0,0 +1,134 @@
+//
+//   This Source Code Form is subject to the terms of the Mozilla Public
+//   License, v. 2.0. If a copy of the MPL was not distributed with this
+//   file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+use std::{borrow::Cow, fmt::Display};
+
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "serde_json")]
+use serde_json::Number;
+
+/// Restrictions on the values a type accepts, beyond what its [`TypeKind`](crate::TypeKind)
+/// already implies
+///
+/// Lengths count the characters of strings, the elements of arrays and the entries of maps and
+/// structs. Numeric bounds are only available with the `serde_json` feature.
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq, Eq, Hash, Clone)]
+pub struct Constraints {
+    #[cfg(feature = "serde_json")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    min: Option<Number>,
+    #[cfg(feature = "serde_json")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    max: Option<Number>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    min_len: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    max_len: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pattern: Option<Cow<'static, str>>,
+}
+
+impl Constraints {
+    /// Create a new set of constraints which accepts any value
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the smallest accepted number, inclusive
+    #[cfg(feature = "serde_json")]
+    #[must_use]
+    pub fn with_min(mut self, min: impl Into<Number>) -> Self {
+        self.min = Some(min.into());
+        self
+    }
+
+    /// Set the largest accepted number, inclusive
+    #[cfg(feature = "serde_json")]
+    #[must_use]
+    pub fn with_max(mut self, max: impl Into<Number>) -> Self {
+        self.max = Some(max.into());
+        self
+    }
+
+    /// Set the smallest accepted length, inclusive
+    #[must_use]
+    pub fn with_min_len(mut self, min_len: usize) -> Self {
+        self.min_len = Some(min_len);
+        self
+    }
+
+    /// Set the largest accepted length, inclusive
+    #[must_use]
+    pub fn with_max_len(mut self, max_len: usize) -> Self {
+        self.max_len = Some(max_len);
+        self
+    }
+
+    /// Set a regular expression that accepted strings have to match
+    #[must_use]
+    pub fn with_pattern(mut self, pattern: impl Into<Cow<'static, str>>) -> Self {
+        self.pattern = Some(pattern.into());
+        self
+    }
+
+    /// The smallest accepted number
+    #[cfg(feature = "serde_json")]
+    pub fn min(&self) -> Option<&Number> {
+        self.min.as_ref()
+    }
+
+    /// The largest accepted number
+    #[cfg(feature = "serde_json")]
+    pub fn max(&self) -> Option<&Number> {
+        self.max.as_ref()
+    }
+
+    /// The smallest accepted length
+    pub fn min_len(&self) -> Option<usize> {
+        self.min_len
+    }
+
+    /// The largest accepted length
+    pub fn max_len(&self) -> Option<usize> {
+        self.max_len
+    }
+
+    /// The regular expression accepted strings have to match
+    pub fn pattern(&self) -> Option<&str> {
+        self.pattern.as_deref()
+    }
+
+    /// Whether no constraint is set
+    pub fn is_empty(&self) -> bool {
+        self == &Self::default()
+    }
+}
+
+impl Display for Constraints {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut parts = vec![];
+        #[cfg(feature = "serde_json")]
+        {
+            if let Some(min) = &self.min {
+                parts.push(format!("min: {min}"));
+            }
+            if let Some(max) = &self.max {
+                parts.push(format!("max: {max}"));
+            }
+        }
+        if let Some(min_len) = self.min_len {
+            parts.push(format!("min length: {min_len}"));
+        }
+        if let Some(max_len) = self.max_len {
+            parts.push(format!("max length: {max_len}"));
+        }
+        if let Some(pattern) = &self.pattern {
+            parts.push(format!("pattern: `{pattern}`"));
+        }
+        write!(f, "{}", parts.join(", "))
+    }
+}
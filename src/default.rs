@@ -0,0 +1,91 @@
+//
+//   This Source Code Form is subject to the terms of the Mozilla Public
+//   License, v. 2.0. If a copy of the MPL was not distributed with this
+//   file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+#[cfg(feature = "serde_json")]
+use std::hash::{Hash, Hasher};
+
+#[cfg(feature = "serde_json")]
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::StructField;
+
+/// The default value of a [`StructField`](crate::StructField), in its serialized form
+#[cfg(feature = "serde_json")]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[serde(transparent)]
+pub struct DefaultValue(serde_json::Value);
+
+#[cfg(feature = "serde_json")]
+impl DefaultValue {
+    /// Create a new [`DefaultValue`] from an already serialized value
+    pub fn new(value: serde_json::Value) -> Self {
+        Self(value)
+    }
+
+    /// Serialize the given value, returns `None` if it cannot be represented
+    pub fn serialize<T: Serialize + ?Sized>(value: &T) -> Option<Self> {
+        serde_json::to_value(value).ok().map(Self)
+    }
+
+    /// Get the serialized value
+    pub fn value(&self) -> &serde_json::Value {
+        &self.0
+    }
+}
+
+#[cfg(feature = "serde_json")]
+impl Hash for DefaultValue {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // `serde_json::Value` is not `Hash`, but its canonical string form is
+        self.0.to_string().hash(state);
+    }
+}
+
+#[cfg(feature = "serde_json")]
+impl std::fmt::Display for DefaultValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// Wraps a default value so that the derive macro can serialize it if, and only if, it
+/// implements [`Serialize`]
+///
+/// Call it as `(&DefaultWrap(&value)).__with_default_value(field)` with both
+/// [`SerializeDefault`] and [`NoDefault`] in scope. Method resolution prefers the former and
+/// falls back to the latter. Without the `serde_json` feature, default values are not captured.
+pub struct DefaultWrap<'a, T>(pub &'a T);
+
+/// Serializes the wrapped value, see [`DefaultWrap`]
+pub trait SerializeDefault {
+    /// Set the serialized default value on the given field
+    fn __with_default_value(&self, field: StructField) -> StructField;
+}
+
+impl<'a, T: Serialize> SerializeDefault for DefaultWrap<'a, T> {
+    #[cfg(feature = "serde_json")]
+    fn __with_default_value(&self, field: StructField) -> StructField {
+        field.with_default_value(DefaultValue::serialize(self.0))
+    }
+
+    #[cfg(not(feature = "serde_json"))]
+    fn __with_default_value(&self, field: StructField) -> StructField {
+        field
+    }
+}
+
+/// Fallback for values which cannot be serialized, see [`DefaultWrap`]
+pub trait NoDefault {
+    /// Return the field unchanged
+    fn __with_default_value(&self, field: StructField) -> StructField;
+}
+
+impl<'a, T> NoDefault for &DefaultWrap<'a, T> {
+    fn __with_default_value(&self, field: StructField) -> StructField {
+        field
+    }
+}
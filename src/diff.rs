@@ -0,0 +1,744 @@
+//
+//   This Source Code Form is subject to the terms of the Mozilla Public
+//   License, v. 2.0. If a copy of the MPL was not distributed with this
+//   file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+use std::{collections::BTreeMap, fmt::Display};
+
+use crate::{
+    registry::named_types, BytesEncoding, EnumVariantRepresentation, Sign, TypeDescription,
+    TypeEnumKind, TypeKind,
+};
+
+/// How a description changed between two versions
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum ChangeKind {
+    /// A field was added to a struct
+    FieldAdded {
+        /// Whether existing values now lack a field they must have
+        required: bool,
+    },
+    /// A field was removed from a struct
+    FieldRemoved,
+    /// An optional field became required
+    FieldMadeRequired,
+    /// The value is now of an entirely different kind
+    KindChanged {
+        /// A short description of the old kind
+        old: String,
+        /// A short description of the new kind
+        new: String,
+    },
+    /// An integer can no longer hold every value it could hold before
+    IntegerNarrowed {
+        /// The old sign and size in bits
+        old: (Sign, u8),
+        /// The new sign and size in bits
+        new: (Sign, u8),
+    },
+    /// A float has fewer bits than before
+    FloatNarrowed {
+        /// The old size in bits
+        old: u8,
+        /// The new size in bits
+        new: u8,
+    },
+    /// A variant was added to an enum
+    VariantAdded {
+        /// The name of the new variant
+        name: String,
+    },
+    /// A variant was removed from an enum
+    VariantRemoved {
+        /// The name of the removed variant
+        name: String,
+    },
+    /// The tag or content field of an enum was renamed
+    TagRenamed {
+        /// The old field name
+        old: String,
+        /// The new field name
+        new: String,
+    },
+    /// The documentation changed
+    DocChanged,
+}
+
+impl ChangeKind {
+    /// Whether values that were valid before the change may be rejected or misread after it
+    ///
+    /// Removed fields count as breaking, as existing values that still set them are either
+    /// rejected or silently ignored.
+    pub fn is_breaking(&self) -> bool {
+        match self {
+            ChangeKind::FieldAdded { required } => *required,
+            ChangeKind::FieldRemoved
+            | ChangeKind::FieldMadeRequired
+            | ChangeKind::KindChanged { .. }
+            | ChangeKind::IntegerNarrowed { .. }
+            | ChangeKind::FloatNarrowed { .. }
+            | ChangeKind::VariantRemoved { .. }
+            | ChangeKind::TagRenamed { .. } => true,
+            ChangeKind::VariantAdded { .. } | ChangeKind::DocChanged => false,
+        }
+    }
+}
+
+fn integer_name((sign, size): &(Sign, u8)) -> String {
+    match sign {
+        Sign::Signed => format!("i{size}"),
+        Sign::Unsigned => format!("u{size}"),
+    }
+}
+
+impl Display for ChangeKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ChangeKind::FieldAdded { required: true } => write!(f, "required field added"),
+            ChangeKind::FieldAdded { required: false } => write!(f, "optional field added"),
+            ChangeKind::FieldRemoved => write!(f, "field removed"),
+            ChangeKind::FieldMadeRequired => write!(f, "field made required"),
+            ChangeKind::KindChanged { old, new } => write!(f, "changed from {old} to {new}"),
+            ChangeKind::IntegerNarrowed { old, new } => write!(
+                f,
+                "integer narrowed from {} to {}",
+                integer_name(old),
+                integer_name(new)
+            ),
+            ChangeKind::FloatNarrowed { old, new } => {
+                write!(f, "float narrowed from f{old} to f{new}")
+            }
+            ChangeKind::VariantAdded { name } => write!(f, "variant `{name}` added"),
+            ChangeKind::VariantRemoved { name } => write!(f, "variant `{name}` removed"),
+            ChangeKind::TagRenamed { old, new } => {
+                write!(f, "tag renamed from `{old}` to `{new}`")
+            }
+            ChangeKind::DocChanged => write!(f, "documentation changed"),
+        }
+    }
+}
+
+/// A single difference between two versions of a [`struct@TypeDescription`]
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Change {
+    path: String,
+    kind: ChangeKind,
+}
+
+impl Change {
+    /// Get the path to the changed value, e.g. `clusters[].cluster.server`
+    ///
+    /// Array elements are written as `[]`, tuple elements as `[0]` and map values as `*`. The
+    /// path is empty if the change concerns the description as a whole.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Get what changed
+    pub fn kind(&self) -> &ChangeKind {
+        &self.kind
+    }
+
+    /// Whether this change may break existing values, see [`ChangeKind::is_breaking`]
+    pub fn is_breaking(&self) -> bool {
+        self.kind.is_breaking()
+    }
+}
+
+impl Display for Change {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.path.is_empty() {
+            write!(f, "{}", self.kind)
+        } else {
+            write!(f, "{}: {}", self.path, self.kind)
+        }
+    }
+}
+
+fn join_field(path: &str, name: &str) -> String {
+    if path.is_empty() {
+        name.to_string()
+    } else {
+        format!("{path}.{name}")
+    }
+}
+
+fn kind_name(kind: &TypeKind) -> String {
+    match kind {
+        TypeKind::Unit => String::from("unit"),
+        TypeKind::Any => String::from("any value"),
+        TypeKind::Bool => String::from("boolean"),
+        TypeKind::Char => String::from("char"),
+        TypeKind::Bytes {
+            encoding: BytesEncoding::Raw,
+        } => String::from("bytes"),
+        TypeKind::Bytes { encoding } => format!("{encoding} bytes"),
+        TypeKind::Integer { sign, size } => integer_name(&(sign.clone(), *size)),
+        TypeKind::Float { size } => format!("f{size}"),
+        TypeKind::String => String::from("string"),
+        TypeKind::FormattedString(format) => format!("{format} string"),
+        TypeKind::Wrapped(inner) => kind_name(inner.kind()),
+        TypeKind::Optional(inner) => format!("optional {}", kind_name(inner.kind())),
+        TypeKind::Array(_) => String::from("array"),
+        TypeKind::FixedArray { length, .. } => format!("array of length {length}"),
+        TypeKind::Tuple(elements) => format!("tuple of {} elements", elements.len()),
+        TypeKind::HashMap { .. } => String::from("map"),
+        TypeKind::Struct(_) => String::from("struct"),
+        TypeKind::Enum(TypeEnumKind::External, _) => String::from("externally tagged enum"),
+        TypeKind::Enum(TypeEnumKind::Tagged(_), _) => String::from("internally tagged enum"),
+        TypeKind::Enum(TypeEnumKind::Adjacent { .. }, _) => String::from("adjacently tagged enum"),
+        TypeKind::Enum(TypeEnumKind::Untagged, _) => String::from("untagged enum"),
+        TypeKind::Reference(name) => format!("`{name}`"),
+    }
+}
+
+/// Whether every integer of the old sign and size also fits into the new one
+fn integer_fits(old: (&Sign, u8), new: (&Sign, u8)) -> bool {
+    match (old, new) {
+        ((Sign::Signed, _), (Sign::Unsigned, _)) => false,
+        ((Sign::Unsigned, old), (Sign::Signed, new)) => new > old,
+        ((_, old), (_, new)) => new >= old,
+    }
+}
+
+/// Skip over references and wrappers to the type that determines the representation
+fn resolve<'a>(
+    definitions: &BTreeMap<&'a str, &'a TypeDescription>,
+    desc: &'a TypeDescription,
+) -> &'a TypeDescription {
+    match desc.kind() {
+        TypeKind::Reference(name) => match definitions.get(name.as_str()) {
+            Some(definition) => resolve(definitions, definition),
+            None => desc,
+        },
+        TypeKind::Wrapped(inner) => resolve(definitions, inner),
+        _ => desc,
+    }
+}
+
+struct Differ<'a> {
+    old_definitions: BTreeMap<&'a str, &'a TypeDescription>,
+    new_definitions: BTreeMap<&'a str, &'a TypeDescription>,
+    /// The pairs of structs and enums currently being compared, to stop at recursive types
+    in_progress: Vec<(&'a str, &'a str)>,
+    changes: Vec<Change>,
+}
+
+impl<'a> Differ<'a> {
+    fn change(&mut self, path: &str, kind: ChangeKind) {
+        self.changes.push(Change {
+            path: path.to_string(),
+            kind,
+        });
+    }
+
+    fn compare_docs(&mut self, path: &str, old: Option<&str>, new: Option<&str>) {
+        if old.map(str::trim) != new.map(str::trim) {
+            self.change(path, ChangeKind::DocChanged);
+        }
+    }
+
+    fn compare(&mut self, old: &'a TypeDescription, new: &'a TypeDescription, path: &str) {
+        // Only user defined types have their own documentation, that of primitives is generated
+        if let (TypeKind::Wrapped(_), TypeKind::Wrapped(_)) = (old.kind(), new.kind()) {
+            self.compare_docs(path, old.doc(), new.doc());
+        }
+
+        let old = resolve(&self.old_definitions, old);
+        let new = resolve(&self.new_definitions, new);
+
+        match (old.kind(), new.kind()) {
+            (TypeKind::Unit, TypeKind::Unit)
+            | (TypeKind::Bool, TypeKind::Bool)
+            | (TypeKind::Char, TypeKind::Char)
+            | (TypeKind::String, TypeKind::String)
+            | (TypeKind::FormattedString(_), TypeKind::String) => {}
+            // Every value that was accepted before is still accepted
+            (_, TypeKind::Any)
+            | (TypeKind::Unit, TypeKind::Optional(_))
+            | (TypeKind::Integer { .. }, TypeKind::Float { .. }) => {}
+            (TypeKind::Optional(old_inner), TypeKind::Optional(new_inner)) => {
+                self.compare(old_inner, new_inner, path);
+            }
+            (_, TypeKind::Optional(new_inner)) => self.compare(old, new_inner, path),
+            (TypeKind::Float { size: old_size }, TypeKind::Float { size: new_size }) => {
+                if new_size < old_size {
+                    self.change(
+                        path,
+                        ChangeKind::FloatNarrowed {
+                            old: *old_size,
+                            new: *new_size,
+                        },
+                    );
+                }
+            }
+            (
+                TypeKind::Integer {
+                    sign: old_sign,
+                    size: old_size,
+                },
+                TypeKind::Integer {
+                    sign: new_sign,
+                    size: new_size,
+                },
+            ) => {
+                if !integer_fits((old_sign, *old_size), (new_sign, *new_size)) {
+                    self.change(
+                        path,
+                        ChangeKind::IntegerNarrowed {
+                            old: (old_sign.clone(), *old_size),
+                            new: (new_sign.clone(), *new_size),
+                        },
+                    );
+                }
+            }
+            (TypeKind::FormattedString(old_format), TypeKind::FormattedString(new_format)) => {
+                if old_format != new_format {
+                    self.kind_changed(old, new, path);
+                }
+            }
+            (
+                TypeKind::Bytes {
+                    encoding: old_encoding,
+                },
+                TypeKind::Bytes {
+                    encoding: new_encoding,
+                },
+            ) => {
+                if old_encoding != new_encoding {
+                    self.kind_changed(old, new, path);
+                }
+            }
+            (TypeKind::Array(old_element), TypeKind::Array(new_element)) => {
+                self.compare(old_element, new_element, &format!("{path}[]"));
+            }
+            (
+                TypeKind::FixedArray {
+                    element: old_element,
+                    length: old_length,
+                },
+                TypeKind::FixedArray {
+                    element: new_element,
+                    length: new_length,
+                },
+            ) => {
+                if old_length != new_length {
+                    self.kind_changed(old, new, path);
+                }
+                self.compare(old_element, new_element, &format!("{path}[]"));
+            }
+            (TypeKind::Tuple(old_elements), TypeKind::Tuple(new_elements)) => {
+                if old_elements.len() != new_elements.len() {
+                    self.kind_changed(old, new, path);
+                }
+                for (idx, (old_element, new_element)) in
+                    old_elements.iter().zip(new_elements).enumerate()
+                {
+                    self.compare(old_element, new_element, &format!("{path}[{idx}]"));
+                }
+            }
+            (
+                TypeKind::HashMap {
+                    key: old_key,
+                    value: old_value,
+                },
+                TypeKind::HashMap {
+                    key: new_key,
+                    value: new_value,
+                },
+            ) => {
+                let value_path = join_field(path, "*");
+                self.compare(old_key, new_key, &value_path);
+                self.compare(old_value, new_value, &value_path);
+            }
+            (TypeKind::Struct(_), TypeKind::Struct(_))
+            | (TypeKind::Enum(..), TypeKind::Enum(..)) => {
+                let pair = (old.name(), new.name());
+                if self.in_progress.contains(&pair) {
+                    return;
+                }
+
+                self.in_progress.push(pair);
+                self.compare_docs(path, old.doc(), new.doc());
+                self.compare_named(old, new, path);
+                self.in_progress.pop();
+            }
+            _ => self.kind_changed(old, new, path),
+        }
+    }
+
+    fn kind_changed(&mut self, old: &TypeDescription, new: &TypeDescription, path: &str) {
+        self.change(
+            path,
+            ChangeKind::KindChanged {
+                old: kind_name(old.kind()),
+                new: kind_name(new.kind()),
+            },
+        );
+    }
+
+    fn compare_tags(&mut self, old: &str, new: &str, path: &str) {
+        if old != new {
+            self.change(
+                path,
+                ChangeKind::TagRenamed {
+                    old: old.to_string(),
+                    new: new.to_string(),
+                },
+            );
+        }
+    }
+
+    fn compare_named(&mut self, old: &'a TypeDescription, new: &'a TypeDescription, path: &str) {
+        match (old.kind(), new.kind()) {
+            (TypeKind::Struct(old_fields), TypeKind::Struct(new_fields)) => {
+                for old_field in old_fields {
+                    let field_path = join_field(path, old_field.name());
+                    let Some(new_field) = new_fields
+                        .iter()
+                        .find(|field| field.name() == old_field.name())
+                    else {
+                        self.change(&field_path, ChangeKind::FieldRemoved);
+                        continue;
+                    };
+
+                    if old_field.optional() && !new_field.optional() {
+                        self.change(&field_path, ChangeKind::FieldMadeRequired);
+                    }
+                    self.compare_docs(&field_path, old_field.doc(), new_field.doc());
+                    self.compare(old_field.kind(), new_field.kind(), &field_path);
+                }
+
+                for new_field in new_fields {
+                    if !old_fields
+                        .iter()
+                        .any(|field| field.name() == new_field.name())
+                    {
+                        self.change(
+                            &join_field(path, new_field.name()),
+                            ChangeKind::FieldAdded {
+                                required: !new_field.optional(),
+                            },
+                        );
+                    }
+                }
+            }
+            (TypeKind::Enum(old_kind, old_variants), TypeKind::Enum(new_kind, new_variants)) => {
+                match (old_kind, new_kind) {
+                    (TypeEnumKind::External, TypeEnumKind::External)
+                    | (TypeEnumKind::Untagged, TypeEnumKind::Untagged) => (),
+                    (TypeEnumKind::Tagged(old_tag), TypeEnumKind::Tagged(new_tag)) => {
+                        self.compare_tags(old_tag, new_tag, path);
+                    }
+                    (
+                        TypeEnumKind::Adjacent {
+                            tag: old_tag,
+                            content: old_content,
+                        },
+                        TypeEnumKind::Adjacent {
+                            tag: new_tag,
+                            content: new_content,
+                        },
+                    ) => {
+                        self.compare_tags(old_tag, new_tag, path);
+                        self.compare_tags(old_content, new_content, path);
+                    }
+                    _ => return self.kind_changed(old, new, path),
+                }
+
+                for old_variant in old_variants {
+                    let Some(new_variant) = new_variants
+                        .iter()
+                        .find(|variant| variant.name() == old_variant.name())
+                    else {
+                        self.change(
+                            path,
+                            ChangeKind::VariantRemoved {
+                                name: old_variant.name().to_string(),
+                            },
+                        );
+                        continue;
+                    };
+
+                    let variant_path = join_field(path, old_variant.name());
+                    self.compare_docs(&variant_path, old_variant.doc(), new_variant.doc());
+                    match (old_variant.repr(), new_variant.repr()) {
+                        (
+                            EnumVariantRepresentation::String(old_repr),
+                            EnumVariantRepresentation::String(new_repr),
+                        ) => {
+                            if old_repr != new_repr {
+                                self.change(
+                                    path,
+                                    ChangeKind::VariantRemoved {
+                                        name: old_repr.to_string(),
+                                    },
+                                );
+                            }
+                        }
+                        (
+                            EnumVariantRepresentation::Wrapped(old_payload),
+                            EnumVariantRepresentation::Wrapped(new_payload),
+                        ) => self.compare(old_payload, new_payload, &variant_path),
+                        (EnumVariantRepresentation::String(_), _) => self.change(
+                            &variant_path,
+                            ChangeKind::KindChanged {
+                                old: String::from("unit variant"),
+                                new: String::from("variant with content"),
+                            },
+                        ),
+                        (_, EnumVariantRepresentation::String(_)) => self.change(
+                            &variant_path,
+                            ChangeKind::KindChanged {
+                                old: String::from("variant with content"),
+                                new: String::from("unit variant"),
+                            },
+                        ),
+                    }
+                }
+
+                for new_variant in new_variants {
+                    if !old_variants
+                        .iter()
+                        .any(|variant| variant.name() == new_variant.name())
+                    {
+                        self.change(
+                            path,
+                            ChangeKind::VariantAdded {
+                                name: new_variant.name().to_string(),
+                            },
+                        );
+                    }
+                }
+            }
+            _ => unreachable!("only structs and enums are compared by name"),
+        }
+    }
+}
+
+/// Compare an old and a new version of a [`struct@TypeDescription`]
+///
+/// Every difference is returned with the path to the value it concerns, see [`Change::path`].
+/// Fields and variants are matched up by name, so a renamed field shows up as removed and added.
+/// Use [`Change::is_breaking`] to find the changes that may break existing values, for example to
+/// fail a CI job when a configuration format changes incompatibly.
+///
+/// # Example
+///
+/// ```rust
+///     use type_description::{AsTypeDescription, TypeDescription};
+///     use type_description::diff::diff;
+///
+///     let old: TypeDescription = serde_json::from_str(
+///         &serde_json::to_string(&u32::as_type_description()).unwrap(),
+///     )
+///     .unwrap();
+///
+///     let changes = diff(&old, &u16::as_type_description());
+///
+///     assert!(changes[0].is_breaking());
+///     assert_eq!(changes[0].to_string(), "integer narrowed from u32 to u16");
+/// ```
+pub fn diff(old: &TypeDescription, new: &TypeDescription) -> Vec<Change> {
+    let mut differ = Differ {
+        old_definitions: named_types(old),
+        new_definitions: named_types(new),
+        in_progress: vec![],
+        changes: vec![],
+    };
+    differ.compare(old, new, "");
+    differ.changes
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        AsTypeDescription, EnumVariant, EnumVariantRepresentation, Sign, StructField,
+        TypeDescription, TypeEnumKind, TypeKind,
+    };
+
+    use super::{diff, ChangeKind};
+
+    fn cluster(fields: Vec<StructField>) -> TypeDescription {
+        TypeDescription::new(
+            String::from("Config"),
+            TypeKind::Struct(vec![StructField::new(
+                "clusters",
+                None,
+                TypeDescription::new(
+                    String::from("Array of 'Cluster's"),
+                    TypeKind::Array(Box::new(TypeDescription::new(
+                        String::from("Cluster"),
+                        TypeKind::Struct(fields),
+                        None,
+                    ))),
+                    None,
+                ),
+                false,
+            )]),
+            None,
+        )
+    }
+
+    fn auth(enum_kind: TypeEnumKind, variants: &[&'static str]) -> TypeDescription {
+        TypeDescription::new(
+            String::from("Auth"),
+            TypeKind::Enum(
+                enum_kind,
+                variants
+                    .iter()
+                    .map(|name| {
+                        EnumVariant::new(
+                            name,
+                            None,
+                            EnumVariantRepresentation::String((*name).into()),
+                        )
+                    })
+                    .collect(),
+            ),
+            None,
+        )
+    }
+
+    #[test]
+    fn classifies_field_changes() {
+        let old = cluster(vec![
+            StructField::new(
+                "server",
+                Some("The server"),
+                String::as_type_description(),
+                false,
+            ),
+            StructField::new("port", None, u32::as_type_description(), true),
+            StructField::new("timeout", None, u64::as_type_description(), false),
+        ]);
+        let new = cluster(vec![
+            StructField::new(
+                "server",
+                Some("The host"),
+                String::as_type_description(),
+                false,
+            ),
+            StructField::new("port", None, u16::as_type_description(), false),
+            StructField::new("user", None, String::as_type_description(), false),
+            StructField::new("retries", None, u8::as_type_description(), true),
+        ]);
+
+        let changes = diff(&old, &new)
+            .into_iter()
+            .map(|change| (change.path().to_string(), change.kind().clone()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            changes,
+            [
+                (String::from("clusters[].server"), ChangeKind::DocChanged),
+                (
+                    String::from("clusters[].port"),
+                    ChangeKind::FieldMadeRequired
+                ),
+                (
+                    String::from("clusters[].port"),
+                    ChangeKind::IntegerNarrowed {
+                        old: (Sign::Unsigned, 32),
+                        new: (Sign::Unsigned, 16)
+                    }
+                ),
+                (String::from("clusters[].timeout"), ChangeKind::FieldRemoved),
+                (
+                    String::from("clusters[].user"),
+                    ChangeKind::FieldAdded { required: true }
+                ),
+                (
+                    String::from("clusters[].retries"),
+                    ChangeKind::FieldAdded { required: false }
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn classifies_enum_changes() {
+        let old = auth(TypeEnumKind::Tagged("type".into()), &["Gcp", "Token"]);
+        let new = auth(TypeEnumKind::Tagged("kind".into()), &["Gcp", "Oidc"]);
+
+        let changes = diff(&old, &new)
+            .into_iter()
+            .map(|change| change.to_string())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            changes,
+            [
+                "tag renamed from `type` to `kind`",
+                "variant `Token` removed",
+                "variant `Oidc` added",
+            ]
+        );
+
+        let changes = diff(&old, &auth(TypeEnumKind::Untagged, &["Gcp", "Token"]));
+        assert_eq!(
+            changes[0].kind(),
+            &ChangeKind::KindChanged {
+                old: String::from("internally tagged enum"),
+                new: String::from("untagged enum")
+            }
+        );
+    }
+
+    #[test]
+    fn compatible_changes_are_not_breaking() {
+        let old = cluster(vec![StructField::new(
+            "port",
+            None,
+            u16::as_type_description(),
+            false,
+        )]);
+        let new = cluster(vec![
+            StructField::new("port", None, Option::<u32>::as_type_description(), true),
+            StructField::new("user", None, String::as_type_description(), true),
+        ]);
+
+        assert_eq!(diff(&old, &old), []);
+        assert!(diff(&old, &new).iter().all(|change| !change.is_breaking()));
+        assert!(diff(&new, &old).iter().all(|change| change.is_breaking()));
+    }
+
+    #[test]
+    fn classifies_numeric_changes() {
+        let changes = diff(&f64::as_type_description(), &f32::as_type_description());
+        assert_eq!(
+            changes[0].kind(),
+            &ChangeKind::FloatNarrowed { old: 64, new: 32 }
+        );
+        assert!(changes[0].is_breaking());
+        assert_eq!(changes[0].to_string(), "float narrowed from f64 to f32");
+
+        assert_eq!(
+            diff(&f32::as_type_description(), &f64::as_type_description()),
+            []
+        );
+        assert_eq!(
+            diff(&u32::as_type_description(), &f64::as_type_description()),
+            []
+        );
+        assert!(diff(&f64::as_type_description(), &u32::as_type_description())[0].is_breaking());
+    }
+
+    #[test]
+    fn unit_may_become_optional() {
+        assert_eq!(
+            diff(
+                &<()>::as_type_description(),
+                &Option::<u8>::as_type_description()
+            ),
+            []
+        );
+        assert!(diff(
+            &Option::<u8>::as_type_description(),
+            &<()>::as_type_description()
+        )[0]
+        .is_breaking());
+    }
+}
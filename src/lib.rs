@@ -16,29 +16,161 @@ use serde::{Deserialize, Serialize};
 /// A derive macro that helps implementing [`AsTypeDescription`]
 pub use type_description_derive::TypeDescription;
 
+mod builder;
+pub use builder::{EnumBuilder, StructBuilder};
+
+mod constraints;
+pub use constraints::Constraints;
+
+mod default;
+#[cfg(feature = "serde_json")]
+pub use default::DefaultValue;
+
+mod path;
+pub use path::{DescriptionPath, Lookup, ParsePathError};
+
+mod registry;
+pub use registry::{describe_recursive, TypeRegistry};
+
+/// Implementation details of the derive macro, not part of the public API
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::default::{DefaultWrap, NoDefault, SerializeDefault};
+
+    use crate::{BytesEncoding, Constraints, TypeDescription, TypeKind};
+
+    /// A numeric bound given in a `#[description(min = ..)]` or `#[description(max = ..)]`
+    pub enum Bound {
+        /// An integer that fits an `i64`
+        Signed(i64),
+        /// An integer that only fits a `u64`
+        Unsigned(u64),
+        /// A finite floating point number
+        Float(f64),
+    }
+
+    #[cfg(feature = "serde_json")]
+    impl From<Bound> for serde_json::Number {
+        fn from(bound: Bound) -> Self {
+            match bound {
+                Bound::Signed(number) => number.into(),
+                Bound::Unsigned(number) => number.into(),
+                Bound::Float(number) => serde_json::Number::from_f64(number).unwrap(),
+            }
+        }
+    }
+
+    /// Add the given bounds, they are only kept with the `serde_json` feature
+    pub fn with_bounds(
+        constraints: Constraints,
+        min: Option<Bound>,
+        max: Option<Bound>,
+    ) -> Constraints {
+        #[cfg(feature = "serde_json")]
+        {
+            let constraints = match min {
+                Some(min) => constraints.with_min(min),
+                None => constraints,
+            };
+            match max {
+                Some(max) => constraints.with_max(max),
+                None => constraints,
+            }
+        }
+        #[cfg(not(feature = "serde_json"))]
+        {
+            let _ = (min, max);
+            constraints
+        }
+    }
+
+    /// Describe a field of the given type as bytes, keeping it optional if it was
+    pub fn bytes(desc: TypeDescription, encoding: BytesEncoding) -> TypeDescription {
+        match desc.kind() {
+            TypeKind::Optional(_) => {
+                let bytes = TypeDescription::bytes(encoding);
+                TypeDescription::new_owned(
+                    format!("An optional '{}'", bytes.name()),
+                    TypeKind::Optional(Box::new(bytes)),
+                    None,
+                )
+            }
+            _ => TypeDescription::bytes(encoding),
+        }
+    }
+}
+
 /// Rendering support for [`struct@TypeDescription`]s
-#[cfg(any(feature = "render_markdown", feature = "render_terminal"))]
+#[cfg(any(
+    feature = "render_markdown",
+    feature = "render_terminal",
+    feature = "render_json_schema",
+    feature = "render_toml",
+    feature = "render_yaml",
+    feature = "render_html"
+))]
 pub mod render;
 
+/// Comparison of two versions of a [`struct@TypeDescription`]
+pub mod diff;
+
+/// Walking and rewriting [`struct@TypeDescription`] trees
+pub mod visit;
+
+/// Validation of values against [`struct@TypeDescription`]s
+#[cfg(feature = "validate")]
+pub mod validate;
+
 /// Generic description of a type
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Hash, Clone)]
 pub struct TypeDescription {
     name: String,
     kind: TypeKind,
     doc: Option<Cow<'static, str>>,
+    #[serde(default, skip_serializing_if = "Constraints::is_empty")]
+    constraints: Constraints,
 }
 
 impl TypeDescription {
     /// Construct a new generic type description
     #[must_use]
     pub fn new(name: String, kind: TypeKind, doc: Option<&'static str>) -> Self {
+        Self::new_owned(name, kind, doc.map(Cow::Borrowed))
+    }
+
+    /// Construct a new generic type description from names and docs only known at runtime
+    #[must_use]
+    pub fn new_owned(
+        name: impl Into<String>,
+        kind: TypeKind,
+        doc: Option<Cow<'static, str>>,
+    ) -> Self {
         Self {
-            name,
+            name: name.into(),
             kind,
-            doc: doc.map(Cow::Borrowed),
+            doc,
+            constraints: Constraints::default(),
         }
     }
 
+    /// Construct a [`TypeKind::Reference`] to the named type with the given name
+    #[must_use]
+    pub fn reference(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            kind: TypeKind::Reference(name.to_string()),
+            doc: None,
+            constraints: Constraints::default(),
+        }
+    }
+
+    /// Replace the constraints on the values of this type
+    #[must_use]
+    pub fn with_constraints(mut self, constraints: Constraints) -> Self {
+        self.constraints = constraints;
+        self
+    }
+
     /// Get a reference to the type's documentation.
     #[must_use]
     pub fn doc(&self) -> Option<&str> {
@@ -56,6 +188,37 @@ impl TypeDescription {
     pub fn name(&self) -> &str {
         &self.name
     }
+
+    /// Get the constraints on the values of this type.
+    #[must_use]
+    pub fn constraints(&self) -> &Constraints {
+        &self.constraints
+    }
+
+    /// Set the type's name.
+    pub fn set_name(&mut self, name: impl Into<String>) {
+        self.name = name.into();
+    }
+
+    /// Set the type's documentation.
+    pub fn set_doc(&mut self, doc: Option<Cow<'static, str>>) {
+        self.doc = doc;
+    }
+
+    /// Set the type's kind.
+    pub fn set_kind(&mut self, kind: TypeKind) {
+        self.kind = kind;
+    }
+
+    /// Get a mutable reference to the type's kind.
+    pub fn kind_mut(&mut self) -> &mut TypeKind {
+        &mut self.kind
+    }
+
+    /// Set the constraints on the values of this type.
+    pub fn set_constraints(&mut self, constraints: Constraints) {
+        self.constraints = constraints;
+    }
 }
 
 /// Representation of an enum
@@ -72,8 +235,21 @@ pub enum EnumVariantRepresentation {
 /// The kind of enum tagging used by the [`TypeKind::Enum`]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Hash, Clone)]
 pub enum TypeEnumKind {
+    /// An external tag, the variant's content is the single value of a map with the variant's name
+    /// as its key
+    ///
+    /// This is what serde uses by default. Variants without content are represented by their
+    /// name.
+    External,
     /// An internal tag with the given tag name
     Tagged(Cow<'static, str>),
+    /// An adjacent tag, the variant's name and its content are placed in two separate fields
+    Adjacent {
+        /// The name of the field containing the variant's name
+        tag: Cow<'static, str>,
+        /// The name of the field containing the variant's content
+        content: Cow<'static, str>,
+    },
     /// An untagged enum variant
     Untagged,
 }
@@ -85,6 +261,9 @@ pub struct StructField {
     doc: Option<Cow<'static, str>>,
     kind: TypeDescription,
     optional: bool,
+    #[cfg(feature = "serde_json")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    default: Option<DefaultValue>,
 }
 
 impl StructField {
@@ -94,15 +273,35 @@ impl StructField {
         doc: Option<&'static str>,
         kind: TypeDescription,
         optional: bool,
+    ) -> Self {
+        Self::new_owned(name, doc.map(Cow::Borrowed), kind, optional)
+    }
+
+    /// Create a new [`StructField`] from a name and doc only known at runtime
+    pub fn new_owned(
+        name: impl Into<Cow<'static, str>>,
+        doc: Option<Cow<'static, str>>,
+        kind: TypeDescription,
+        optional: bool,
     ) -> Self {
         Self {
-            name: Cow::Borrowed(name),
-            doc: doc.map(Cow::Borrowed),
+            name: name.into(),
+            doc,
             kind,
             optional,
+            #[cfg(feature = "serde_json")]
+            default: None,
         }
     }
 
+    /// Set the value this field takes when it is omitted
+    #[cfg(feature = "serde_json")]
+    #[must_use]
+    pub fn with_default_value(mut self, default: Option<DefaultValue>) -> Self {
+        self.default = default;
+        self
+    }
+
     /// Get the field's name.
     pub fn name(&self) -> &str {
         self.name.as_ref()
@@ -122,6 +321,43 @@ impl StructField {
     pub fn optional(&self) -> bool {
         self.optional
     }
+
+    /// The value this field takes when it is omitted, if known
+    #[cfg(feature = "serde_json")]
+    pub fn default_value(&self) -> Option<&DefaultValue> {
+        self.default.as_ref()
+    }
+
+    /// Set the field's name.
+    pub fn set_name(&mut self, name: impl Into<Cow<'static, str>>) {
+        self.name = name.into();
+    }
+
+    /// Set the field's doc.
+    pub fn set_doc(&mut self, doc: Option<Cow<'static, str>>) {
+        self.doc = doc;
+    }
+
+    /// Set the field's kind.
+    pub fn set_kind(&mut self, kind: TypeDescription) {
+        self.kind = kind;
+    }
+
+    /// Get a mutable reference to the field's kind.
+    pub fn kind_mut(&mut self) -> &mut TypeDescription {
+        &mut self.kind
+    }
+
+    /// Set whether this field is optional.
+    pub fn set_optional(&mut self, optional: bool) {
+        self.optional = optional;
+    }
+
+    /// Set the value this field takes when it is omitted.
+    #[cfg(feature = "serde_json")]
+    pub fn set_default_value(&mut self, default: Option<DefaultValue>) {
+        self.default = default;
+    }
 }
 
 /// A variant in a [`TypeKind::Enum`]
@@ -138,10 +374,19 @@ impl EnumVariant {
         name: &'static str,
         doc: Option<&'static str>,
         repr: EnumVariantRepresentation,
+    ) -> Self {
+        Self::new_owned(name, doc.map(Cow::Borrowed), repr)
+    }
+
+    /// Create a new [`EnumVariant`] from a name and doc only known at runtime
+    pub fn new_owned(
+        name: impl Into<Cow<'static, str>>,
+        doc: Option<Cow<'static, str>>,
+        repr: EnumVariantRepresentation,
     ) -> Self {
         Self {
-            name: Cow::Borrowed(name),
-            doc: doc.map(Cow::Borrowed),
+            name: name.into(),
+            doc,
             repr,
         }
     }
@@ -160,11 +405,107 @@ impl EnumVariant {
     pub fn repr(&self) -> &EnumVariantRepresentation {
         &self.repr
     }
+
+    /// Set the variant's name.
+    pub fn set_name(&mut self, name: impl Into<Cow<'static, str>>) {
+        self.name = name.into();
+    }
+
+    /// Set the variant's doc.
+    pub fn set_doc(&mut self, doc: Option<Cow<'static, str>>) {
+        self.doc = doc;
+    }
+
+    /// Set the variant's representation.
+    pub fn set_repr(&mut self, repr: EnumVariantRepresentation) {
+        self.repr = repr;
+    }
+
+    /// Get a mutable reference to the variant's representation.
+    pub fn repr_mut(&mut self) -> &mut EnumVariantRepresentation {
+        &mut self.repr
+    }
+}
+
+/// A well known format of a [`TypeKind::FormattedString`]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Copy)]
+#[serde(rename_all = "kebab-case")]
+pub enum StringFormat {
+    /// An IP address and a port, like `127.0.0.1:8080` or `[::1]:8080`
+    SocketAddr,
+    /// An IPv4 or IPv6 address
+    Ip,
+    /// An IPv4 address, like `127.0.0.1`
+    Ipv4,
+    /// An IPv6 address, like `::1`
+    Ipv6,
+    /// A URL, like `https://example.com/path`
+    Url,
+    /// A UUID, like `67e55044-10b1-426f-9247-bb680e5fe0c8`
+    Uuid,
+    /// A filesystem path
+    Path,
+    /// A number of bytes with an optional unit, like `512 KiB`
+    ByteSize,
+    /// A span of time, like `5s` or `1h 30m`
+    Duration,
+    /// A point in time as described by RFC 3339, like `1996-12-19T16:39:57-08:00`
+    DateTime,
+}
+
+impl std::fmt::Display for StringFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            StringFormat::SocketAddr => "socket-addr",
+            StringFormat::Ip => "ip",
+            StringFormat::Ipv4 => "ipv4",
+            StringFormat::Ipv6 => "ipv6",
+            StringFormat::Url => "url",
+            StringFormat::Uuid => "uuid",
+            StringFormat::Path => "path",
+            StringFormat::ByteSize => "byte-size",
+            StringFormat::Duration => "duration",
+            StringFormat::DateTime => "date-time",
+        };
+        f.write_str(name)
+    }
+}
+
+/// How the bytes of a [`TypeKind::Bytes`] are written
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Copy)]
+#[serde(rename_all = "kebab-case")]
+pub enum BytesEncoding {
+    /// As is, which most formats write as an array of integers, like `serde_bytes` does
+    Raw,
+    /// As a base64 string, like `aGVsbG8=`
+    Base64,
+    /// As a string of hexadecimal digits, like `68656c6c6f`
+    Hex,
+}
+
+impl std::fmt::Display for BytesEncoding {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            BytesEncoding::Raw => "raw",
+            BytesEncoding::Base64 => "base64",
+            BytesEncoding::Hex => "hex",
+        };
+        f.write_str(name)
+    }
 }
 
 /// The specific kind a [`struct@TypeDescription`] represents
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Hash, Clone)]
 pub enum TypeKind {
+    /// Type represents the absence of a value, like `()` or a JSON `null`
+    Unit,
+
+    /// Type represents any value at all, like a `serde_json::Value`
+    ///
+    /// This is meant for sections that are passed through to something else without being
+    /// interpreted.
+    Any,
+
     /// Type represents a boolean `true`/`false`
     Bool,
 
@@ -183,9 +524,15 @@ pub enum TypeKind {
         size: u8,
     },
 
+    /// Type represents a single unicode character
+    Char,
+
     /// Type represents a string
     String,
 
+    /// Type represents a string in the given well known format
+    FormattedString(StringFormat),
+
     /// Wrap another type
     ///
     /// This is particularly useful if you want to restrict another kind. The common example is a
@@ -193,9 +540,30 @@ pub enum TypeKind {
     /// meant to represent.
     Wrapped(Box<TypeDescription>),
 
+    /// Type represents a value of the given type which may also be left out or `null`, like an
+    /// [`Option`]
+    Optional(Box<TypeDescription>),
+
+    /// Type represents a sequence of bytes, written in the given encoding
+    Bytes {
+        /// How the bytes are written
+        encoding: BytesEncoding,
+    },
+
     /// Type represents an array of values of the given [`TypeKind`]
     Array(Box<TypeDescription>),
 
+    /// Type represents an array of exactly `length` values of the given [`TypeKind`]
+    FixedArray {
+        /// The type of the array's elements
+        element: Box<TypeDescription>,
+        /// The number of elements in the array
+        length: usize,
+    },
+
+    /// Type represents a fixed number of values of the given types, in this order
+    Tuple(Vec<TypeDescription>),
+
     /// Type represents a hashmap of named types of the same type
     HashMap {
         /// The key of the HashMap
@@ -209,6 +577,12 @@ pub enum TypeKind {
 
     /// Type represents multiple choice of type variants
     Enum(TypeEnumKind, Vec<EnumVariant>),
+
+    /// Type refers to the struct or enum with the given name
+    ///
+    /// This is used to describe recursive types, where the name refers to a type further up the
+    /// same description, and by the [`TypeRegistry`] to store every named type only once.
+    Reference(String),
 }
 
 /// Whether an integer is a signed integer or an unsigned integer
@@ -234,53 +608,92 @@ impl<T: AsTypeDescription> AsTypeDescription for Option<T> {
     fn as_type_description() -> TypeDescription {
         TypeDescription::new(
             format!("An optional '{}'", T::as_type_description().name()),
-            TypeKind::Wrapped(Box::new(T::as_type_description())),
+            TypeKind::Optional(Box::new(T::as_type_description())),
             None,
         )
     }
 }
 
+impl<T: AsTypeDescription + ?Sized> AsTypeDescription for Box<T> {
+    fn as_type_description() -> TypeDescription {
+        T::as_type_description()
+    }
+}
+
+impl<T: AsTypeDescription + ?Sized> AsTypeDescription for std::rc::Rc<T> {
+    fn as_type_description() -> TypeDescription {
+        T::as_type_description()
+    }
+}
+
+impl<T: AsTypeDescription + ?Sized> AsTypeDescription for std::sync::Arc<T> {
+    fn as_type_description() -> TypeDescription {
+        T::as_type_description()
+    }
+}
+
 impl<T: AsTypeDescription> AsTypeDescription for Vec<T> {
     fn as_type_description() -> TypeDescription {
-        TypeDescription::new(
-            format!("Array of '{}'s", T::as_type_description().name()),
-            TypeKind::Array(Box::new(T::as_type_description())),
-            None,
-        )
+        TypeDescription::array_of(T::as_type_description())
     }
 }
 
+impl<T: AsTypeDescription, const N: usize> AsTypeDescription for [T; N] {
+    fn as_type_description() -> TypeDescription {
+        TypeDescription::fixed_array_of(T::as_type_description(), N)
+    }
+}
+
+macro_rules! impl_tuple {
+    ($($typ:ident),+) => {
+        impl<$($typ: AsTypeDescription),+> AsTypeDescription for ($($typ,)+) {
+            fn as_type_description() -> TypeDescription {
+                let elements = vec![$($typ::as_type_description()),+];
+                TypeDescription::new(
+                    format!(
+                        "Tuple of ({})",
+                        elements
+                            .iter()
+                            .map(|element| element.name())
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    ),
+                    TypeKind::Tuple(elements),
+                    None,
+                )
+            }
+        }
+    };
+}
+
+impl AsTypeDescription for () {
+    fn as_type_description() -> TypeDescription {
+        TypeDescription::new("Unit".into(), TypeKind::Unit, Some("No value"))
+    }
+}
+
+impl_tuple!(A);
+impl_tuple!(A, B);
+impl_tuple!(A, B, C);
+impl_tuple!(A, B, C, D);
+impl_tuple!(A, B, C, D, E);
+impl_tuple!(A, B, C, D, E, F);
+impl_tuple!(A, B, C, D, E, F, G);
+impl_tuple!(A, B, C, D, E, F, G, H);
+impl_tuple!(A, B, C, D, E, F, G, H, I);
+impl_tuple!(A, B, C, D, E, F, G, H, I, J);
+impl_tuple!(A, B, C, D, E, F, G, H, I, J, K);
+impl_tuple!(A, B, C, D, E, F, G, H, I, J, K, L);
+
 impl<K: AsTypeDescription, V: AsTypeDescription> AsTypeDescription for HashMap<K, V> {
     fn as_type_description() -> TypeDescription {
-        TypeDescription::new(
-            format!(
-                "Table of '{} => {}'",
-                K::as_type_description().name(),
-                V::as_type_description().name()
-            ),
-            TypeKind::HashMap {
-                key: Box::new(K::as_type_description()),
-                value: Box::new(V::as_type_description()),
-            },
-            None,
-        )
+        TypeDescription::map_of(K::as_type_description(), V::as_type_description())
     }
 }
 
 impl<K: AsTypeDescription, V: AsTypeDescription> AsTypeDescription for BTreeMap<K, V> {
     fn as_type_description() -> TypeDescription {
-        TypeDescription::new(
-            format!(
-                "Table of '{} => {}'",
-                K::as_type_description().name(),
-                V::as_type_description().name()
-            ),
-            TypeKind::HashMap {
-                key: Box::new(K::as_type_description()),
-                value: Box::new(V::as_type_description()),
-            },
-            None,
-        )
+        TypeDescription::map_of(K::as_type_description(), V::as_type_description())
     }
 }
 
@@ -340,28 +753,76 @@ impl_config_kind!(TypeKind::Float { size: 64 }; "Float"; "A floating point value
 impl_config_kind!(TypeKind::Float { size: 32 }; "Float"; "A floating point value with 32 bits" => f32);
 
 impl_config_kind!(TypeKind::Bool; "Boolean"; "A boolean" => bool);
+impl_config_kind!(TypeKind::Char; "Character"; "A single unicode character" => char);
 impl_config_kind!(TypeKind::String; "String"; "An UTF-8 string" => String);
 
-impl_config_kind!(TypeKind::String; "String"; "A socket address" => std::net::SocketAddr);
-impl_config_kind!(TypeKind::String; "String"; "An IPv4 socket address" => std::net::SocketAddrV4);
-impl_config_kind!(TypeKind::String; "String"; "An IPv6 socket address" => std::net::SocketAddrV6);
+impl_config_kind!(TypeKind::FormattedString(StringFormat::SocketAddr); "String"; "A socket address" => std::net::SocketAddr);
+impl_config_kind!(TypeKind::FormattedString(StringFormat::SocketAddr); "String"; "An IPv4 socket address" => std::net::SocketAddrV4);
+impl_config_kind!(TypeKind::FormattedString(StringFormat::SocketAddr); "String"; "An IPv6 socket address" => std::net::SocketAddrV6);
+
+impl_config_kind!(TypeKind::FormattedString(StringFormat::Ip); "String"; "An IP address" => std::net::IpAddr);
+impl_config_kind!(TypeKind::FormattedString(StringFormat::Ipv4); "String"; "An IPv4 address" => std::net::Ipv4Addr);
+impl_config_kind!(TypeKind::FormattedString(StringFormat::Ipv6); "String"; "An IPv6 address" => std::net::Ipv6Addr);
 
 #[cfg(feature = "bytesize")]
-impl_config_kind!(TypeKind::String; "String"; "A number of Bytes" => bytesize::ByteSize);
+impl_config_kind!(TypeKind::FormattedString(StringFormat::ByteSize); "String"; "A number of Bytes" => bytesize::ByteSize);
 
 #[cfg(feature = "url")]
-impl_config_kind!(TypeKind::String; "String"; "An URL" => url::Url);
+impl_config_kind!(TypeKind::FormattedString(StringFormat::Url); "String"; "An URL" => url::Url);
 
 #[cfg(feature = "uuid")]
-impl_config_kind!(TypeKind::String; "String"; "A UUID" => uuid::Uuid);
+impl_config_kind!(TypeKind::FormattedString(StringFormat::Uuid); "String"; "A UUID" => uuid::Uuid);
+
+impl_config_kind!(TypeKind::FormattedString(StringFormat::Path); "String"; "A filesystem path" => std::path::PathBuf);
+
+#[cfg(feature = "serde_json")]
+impl_config_kind!(TypeKind::Any; "Any"; "Any JSON value" => serde_json::Value);
+
+#[cfg(feature = "serde_json")]
+impl AsTypeDescription for serde_json::Map<String, serde_json::Value> {
+    fn as_type_description() -> TypeDescription {
+        TypeDescription::map_of(
+            String::as_type_description(),
+            serde_json::Value::as_type_description(),
+        )
+    }
+}
+
+#[cfg(feature = "serde_bytes")]
+impl AsTypeDescription for serde_bytes::ByteBuf {
+    fn as_type_description() -> TypeDescription {
+        TypeDescription::bytes(BytesEncoding::Raw)
+    }
+}
+
+#[cfg(feature = "serde_bytes")]
+impl AsTypeDescription for serde_bytes::Bytes {
+    fn as_type_description() -> TypeDescription {
+        TypeDescription::bytes(BytesEncoding::Raw)
+    }
+}
+
+#[cfg(feature = "toml")]
+impl_config_kind!(TypeKind::Any; "Any"; "Any TOML value" => toml::Value);
 
-impl_config_kind!(TypeKind::String; "String"; "A filesystem path" => std::path::PathBuf);
+#[cfg(feature = "toml")]
+impl AsTypeDescription for toml::Table {
+    fn as_type_description() -> TypeDescription {
+        TypeDescription::map_of(
+            String::as_type_description(),
+            toml::Value::as_type_description(),
+        )
+    }
+}
 
 #[cfg(test)]
 mod tests {
     use std::collections::HashMap;
 
-    use super::{AsTypeDescription, Sign, TypeDescription, TypeKind};
+    use super::{
+        AsTypeDescription, BytesEncoding, EnumVariant, EnumVariantRepresentation, Sign,
+        StringFormat, StructField, TypeDescription, TypeEnumKind, TypeKind,
+    };
 
     #[test]
     fn verify_correct_config_kinds() {
@@ -411,4 +872,125 @@ mod tests {
             other => panic!("Expected HashMap, got {:?}", other),
         }
     }
+
+    #[test]
+    fn test_tuples_and_arrays() {
+        let range = <(u16, u16)>::as_type_description();
+        assert_eq!(range.name(), "Tuple of (Integer, Integer)");
+        assert!(
+            matches!(range.kind(), TypeKind::Tuple(elements) if elements.len() == 2 && matches!(elements[1].kind(), TypeKind::Integer { size: 16, .. }))
+        );
+
+        let vector = <[f32; 3]>::as_type_description();
+        assert_eq!(vector.name(), "Array of 3 'Float's");
+        assert!(
+            matches!(vector.kind(), TypeKind::FixedArray { element, length: 3 } if matches!(element.kind(), TypeKind::Float { size: 32 }))
+        );
+    }
+
+    #[test]
+    fn test_optional() {
+        let optional = Option::<String>::as_type_description();
+        assert_eq!(optional.name(), "An optional 'String'");
+        assert!(
+            matches!(optional.kind(), TypeKind::Optional(inner) if matches!(inner.kind(), TypeKind::String))
+        );
+    }
+
+    #[test]
+    fn test_bytes_and_char() {
+        assert_eq!(char::as_type_description().kind(), &TypeKind::Char);
+
+        let hex = TypeDescription::bytes(BytesEncoding::Hex);
+        assert_eq!(hex.name(), "Bytes");
+        assert_eq!(
+            hex.kind(),
+            &TypeKind::Bytes {
+                encoding: BytesEncoding::Hex
+            }
+        );
+        assert_eq!(BytesEncoding::Base64.to_string(), "base64");
+    }
+
+    #[test]
+    fn test_unit() {
+        assert_eq!(<()>::as_type_description().kind(), &TypeKind::Unit);
+    }
+
+    #[cfg(feature = "serde_json")]
+    #[test]
+    fn test_dynamic_values() {
+        assert_eq!(
+            serde_json::Value::as_type_description().kind(),
+            &TypeKind::Any
+        );
+
+        let extensions = serde_json::Map::<String, serde_json::Value>::as_type_description();
+        assert!(
+            matches!(extensions.kind(), TypeKind::HashMap { value, .. } if value.kind() == &TypeKind::Any)
+        );
+    }
+
+    #[test]
+    fn test_string_formats() {
+        assert_eq!(
+            std::net::SocketAddrV6::as_type_description().kind(),
+            &TypeKind::FormattedString(StringFormat::SocketAddr)
+        );
+        assert_eq!(
+            std::net::IpAddr::as_type_description().kind(),
+            &TypeKind::FormattedString(StringFormat::Ip)
+        );
+        assert_eq!(StringFormat::DateTime.to_string(), "date-time");
+        assert_eq!(
+            serde_json::to_string(&StringFormat::ByteSize).unwrap(),
+            r#""byte-size""#
+        );
+    }
+
+    #[test]
+    fn test_owned_descriptions() {
+        let section = String::from("plugin");
+        let field_doc = format!("Settings of the {section} plugin");
+
+        let mut desc = TypeDescription::new_owned(
+            format!("{section}Config"),
+            TypeKind::Struct(vec![StructField::new_owned(
+                section.clone(),
+                Some(field_doc.into()),
+                bool::as_type_description(),
+                false,
+            )]),
+            None,
+        );
+
+        desc.set_doc(Some(format!("Config of {section}").into()));
+        if let TypeKind::Struct(fields) = desc.kind_mut() {
+            fields[0].set_optional(true);
+            fields[0].set_kind(TypeDescription::new_owned(
+                "Mode",
+                TypeKind::Enum(
+                    TypeEnumKind::External,
+                    vec![EnumVariant::new_owned(
+                        section.to_uppercase(),
+                        None,
+                        EnumVariantRepresentation::String(section.to_uppercase().into()),
+                    )],
+                ),
+                None,
+            ));
+        }
+
+        assert_eq!(desc.name(), "pluginConfig");
+        assert_eq!(desc.doc(), Some("Config of plugin"));
+        let TypeKind::Struct(fields) = desc.kind() else {
+            panic!("Expected Struct, got {:?}", desc.kind());
+        };
+        assert_eq!(fields[0].name(), "plugin");
+        assert_eq!(fields[0].doc(), Some("Settings of the plugin plugin"));
+        assert!(fields[0].optional());
+        assert!(
+            matches!(fields[0].kind().kind(), TypeKind::Enum(_, variants) if variants[0].name() == "PLUGIN")
+        );
+    }
 }
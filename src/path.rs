@@ -0,0 +1,395 @@
+//
+//   This Source Code Form is subject to the terms of the Mozilla Public
+//   License, v. 2.0. If a copy of the MPL was not distributed with this
+//   file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+use std::{collections::BTreeMap, fmt::Display, str::FromStr};
+
+use crate::{
+    registry::named_types, visit::PathSegment, EnumVariant, EnumVariantRepresentation, StructField,
+    TypeDescription, TypeKind,
+};
+
+/// A path from a [`struct@TypeDescription`] to one of the descriptions it contains
+///
+/// Paths are written like `clusters[].cluster.server`, see [`PathSegment`] for every kind of
+/// step. When parsed, every name becomes a [`PathSegment::Field`], as fields and variants are
+/// written the same way.
+#[derive(Debug, Default, PartialEq, Eq, Hash, Clone)]
+pub struct DescriptionPath(Vec<PathSegment>);
+
+impl DescriptionPath {
+    /// Create an empty path, pointing at the description itself
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get the steps of this path
+    pub fn segments(&self) -> &[PathSegment] {
+        &self.0
+    }
+
+    /// Append a step to this path
+    pub fn push(&mut self, segment: PathSegment) {
+        self.0.push(segment);
+    }
+}
+
+impl From<Vec<PathSegment>> for DescriptionPath {
+    fn from(segments: Vec<PathSegment>) -> Self {
+        Self(segments)
+    }
+}
+
+impl From<&[PathSegment]> for DescriptionPath {
+    fn from(segments: &[PathSegment]) -> Self {
+        Self(segments.to_vec())
+    }
+}
+
+impl FromIterator<PathSegment> for DescriptionPath {
+    fn from_iter<T: IntoIterator<Item = PathSegment>>(iter: T) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl Display for DescriptionPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (idx, segment) in self.0.iter().enumerate() {
+            // The first name is written without its leading dot
+            let segment = segment.to_string();
+            match segment.strip_prefix('.') {
+                Some(name) if idx == 0 => f.write_str(name)?,
+                _ => f.write_str(&segment)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The error returned when parsing an invalid [`DescriptionPath`]
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ParsePathError {
+    path: String,
+    reason: &'static str,
+}
+
+impl Display for ParsePathError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid path `{}`: {}", self.path, self.reason)
+    }
+}
+
+impl std::error::Error for ParsePathError {}
+
+impl FromStr for DescriptionPath {
+    type Err = ParsePathError;
+
+    fn from_str(path: &str) -> Result<Self, Self::Err> {
+        let error = |reason| ParsePathError {
+            path: path.to_string(),
+            reason,
+        };
+
+        let mut segments = vec![];
+        let mut rest = path;
+        while !rest.is_empty() {
+            if let Some(bracketed) = rest.strip_prefix('[') {
+                let (index, after) = bracketed
+                    .split_once(']')
+                    .ok_or_else(|| error("unclosed `[`"))?;
+                segments.push(match index {
+                    "" => PathSegment::Element,
+                    index => PathSegment::Index(
+                        index
+                            .parse()
+                            .map_err(|_| error("expected an index between `[` and `]`"))?,
+                    ),
+                });
+                rest = after;
+                continue;
+            }
+
+            let name = match rest.strip_prefix('.') {
+                Some(_) if segments.is_empty() => return Err(error("unexpected leading `.`")),
+                Some(name) => name,
+                None if segments.is_empty() => rest,
+                None => return Err(error("expected `.` or `[`")),
+            };
+            let end = name.find(['.', '[']).unwrap_or(name.len());
+            segments.push(match &name[..end] {
+                "" => return Err(error("empty name")),
+                "*" => PathSegment::Value,
+                "<key>" => PathSegment::Key,
+                name => PathSegment::Field(name.to_string()),
+            });
+            rest = &name[end..];
+        }
+
+        Ok(Self(segments))
+    }
+}
+
+/// The result of [`TypeDescription::lookup`]
+#[derive(Debug, Clone, Copy)]
+pub struct Lookup<'a> {
+    desc: &'a TypeDescription,
+    field: Option<&'a StructField>,
+    variant: Option<&'a EnumVariant>,
+}
+
+impl<'a> Lookup<'a> {
+    /// Get the description the path points to
+    pub fn description(&self) -> &'a TypeDescription {
+        self.desc
+    }
+
+    /// Get the struct field the path ends in, if it ends in one
+    pub fn field(&self) -> Option<&'a StructField> {
+        self.field
+    }
+
+    /// Get the enum variant the path ends in, if it ends in one
+    pub fn variant(&self) -> Option<&'a EnumVariant> {
+        self.variant
+    }
+
+    /// Get the most specific documentation, that of the field or variant before that of the type
+    pub fn doc(&self) -> Option<&'a str> {
+        self.field
+            .and_then(StructField::doc)
+            .or(self.variant.and_then(EnumVariant::doc))
+            .or(self.desc.doc())
+    }
+
+    /// Whether the path ends in a field that may be omitted
+    pub fn optional(&self) -> bool {
+        self.field.map_or(false, StructField::optional)
+    }
+}
+
+struct Resolver<'a> {
+    definitions: BTreeMap<&'a str, &'a TypeDescription>,
+}
+
+impl<'a> Resolver<'a> {
+    /// Skip over references and wrappers to the type that determines the representation
+    fn resolve(&self, desc: &'a TypeDescription) -> &'a TypeDescription {
+        match desc.kind() {
+            TypeKind::Reference(name) => match self.definitions.get(name.as_str()) {
+                Some(definition) => self.resolve(definition),
+                None => desc,
+            },
+            TypeKind::Wrapped(inner) | TypeKind::Optional(inner) => self.resolve(inner),
+            _ => desc,
+        }
+    }
+
+    fn field(&self, desc: &'a TypeDescription, name: &str) -> Option<Lookup<'a>> {
+        let TypeKind::Struct(fields) = self.resolve(desc).kind() else {
+            return None;
+        };
+
+        let field = fields.iter().find(|field| field.name() == name)?;
+        Some(Lookup {
+            desc: field.kind(),
+            field: Some(field),
+            variant: None,
+        })
+    }
+
+    fn step(&self, current: &Lookup<'a>, segment: &PathSegment) -> Option<Lookup<'a>> {
+        let desc = self.resolve(current.desc);
+        let child = |desc| {
+            Some(Lookup {
+                desc,
+                field: None,
+                variant: None,
+            })
+        };
+
+        match (segment, desc.kind()) {
+            (
+                PathSegment::Element | PathSegment::Index(_),
+                TypeKind::Array(element) | TypeKind::FixedArray { element, .. },
+            ) => child(element),
+            (PathSegment::Index(idx), TypeKind::Tuple(elements)) => child(elements.get(*idx)?),
+            (PathSegment::Key, TypeKind::HashMap { key, .. }) => child(key),
+            // A name in a map is any of its keys, as in the paths of validation errors
+            (PathSegment::Value | PathSegment::Field(_), TypeKind::HashMap { value, .. }) => {
+                child(value)
+            }
+            (PathSegment::Field(name), TypeKind::Struct(_)) => self.field(desc, name),
+            (
+                PathSegment::Field(name) | PathSegment::Variant(name),
+                TypeKind::Enum(_, variants),
+            ) => {
+                if let Some(variant) = variants.iter().find(|variant| variant.name() == name) {
+                    return Some(Lookup {
+                        desc: match variant.repr() {
+                            EnumVariantRepresentation::Wrapped(payload) => payload,
+                            EnumVariantRepresentation::String(_) => desc,
+                        },
+                        field: None,
+                        variant: Some(variant),
+                    });
+                }
+
+                // Fields of variants that are not nested below their variant's name
+                variants.iter().find_map(|variant| match variant.repr() {
+                    EnumVariantRepresentation::Wrapped(payload) => self.field(payload, name),
+                    EnumVariantRepresentation::String(_) => None,
+                })
+            }
+            _ => None,
+        }
+    }
+}
+
+impl TypeDescription {
+    /// Find the description at the given path, like `clusters[].cluster.server`
+    ///
+    /// Wrapped types and references are looked through. Array elements can also be given by
+    /// index and map values by key, so the paths of validation errors can be looked up as well.
+    /// A name on an enum selects the variant of that name or else the first variant field of that
+    /// name, which is where internally tagged and untagged enums keep their fields.
+    ///
+    /// Returns `None` if the path is invalid or does not exist.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use type_description::{AsTypeDescription, TypeDescription, TypeKind};
+    ///
+    ///     let desc = TypeDescription::builder("Config")
+    ///         .optional_field(
+    ///             "clusters",
+    ///             TypeDescription::array_of(
+    ///                 TypeDescription::builder("Cluster")
+    ///                     .documented_field("server", "The server to connect to", String::as_type_description())
+    ///                     .build(),
+    ///             ),
+    ///         )
+    ///         .build();
+    ///
+    ///     let server = desc.lookup("clusters[].server").unwrap();
+    ///     assert_eq!(server.doc(), Some("The server to connect to"));
+    ///     assert!(!server.optional());
+    ///     assert!(matches!(server.description().kind(), TypeKind::String));
+    /// ```
+    pub fn lookup(&self, path: &str) -> Option<Lookup<'_>> {
+        self.lookup_path(&path.parse().ok()?)
+    }
+
+    /// Find the description at the given path, see [`TypeDescription::lookup`]
+    pub fn lookup_path(&self, path: &DescriptionPath) -> Option<Lookup<'_>> {
+        let resolver = Resolver {
+            definitions: named_types(self),
+        };
+
+        path.segments().iter().try_fold(
+            Lookup {
+                desc: self,
+                field: None,
+                variant: None,
+            },
+            |current, segment| resolver.step(&current, segment),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{AsTypeDescription, TypeDescription, TypeEnumKind, TypeKind};
+
+    use crate::visit::PathSegment;
+
+    use super::DescriptionPath;
+
+    #[test]
+    fn parse_paths() {
+        let path: DescriptionPath = "clusters[].cluster[2].labels.*.<key>".parse().unwrap();
+
+        assert_eq!(
+            path.segments(),
+            [
+                PathSegment::Field(String::from("clusters")),
+                PathSegment::Element,
+                PathSegment::Field(String::from("cluster")),
+                PathSegment::Index(2),
+                PathSegment::Field(String::from("labels")),
+                PathSegment::Value,
+                PathSegment::Key,
+            ]
+        );
+        assert_eq!(path.to_string(), "clusters[].cluster[2].labels.*.<key>");
+        assert_eq!("[]".parse::<DescriptionPath>().unwrap().to_string(), "[]");
+        assert_eq!("".parse(), Ok(DescriptionPath::new()));
+
+        for invalid in ["a..b", "a[", "a[x]", "a[]b", ".a"] {
+            assert!(invalid.parse::<DescriptionPath>().is_err(), "{invalid}");
+        }
+    }
+
+    fn config() -> TypeDescription {
+        let cluster = TypeDescription::builder("Cluster")
+            .documented_field(
+                "server",
+                "The server to connect to",
+                String::as_type_description(),
+            )
+            .optional_field("port", u16::as_type_description())
+            .build();
+
+        TypeDescription::builder("Config")
+            .field(
+                "clusters",
+                TypeDescription::map_of(
+                    String::as_type_description(),
+                    TypeDescription::array_of(cluster),
+                ),
+            )
+            .field(
+                "auth",
+                TypeDescription::enum_builder("Auth")
+                    .tagging(TypeEnumKind::Tagged("type".into()))
+                    .unit_variant("Anonymous")
+                    .variant(
+                        "Token",
+                        TypeDescription::builder("Token")
+                            .documented_field("token", "The token", String::as_type_description())
+                            .build(),
+                    )
+                    .build(),
+            )
+            .build()
+    }
+
+    #[test]
+    fn lookup_paths() {
+        let desc = config();
+
+        let server = desc.lookup("clusters.*[].server").unwrap();
+        assert_eq!(server.doc(), Some("The server to connect to"));
+        assert!(!server.optional());
+
+        let port = desc.lookup("clusters.prod[3].port").unwrap();
+        assert!(port.optional());
+        assert!(matches!(
+            port.description().kind(),
+            TypeKind::Integer { size: 16, .. }
+        ));
+
+        assert_eq!(
+            desc.lookup("auth.Token").unwrap().variant().unwrap().name(),
+            "Token"
+        );
+        assert_eq!(desc.lookup("auth.token").unwrap().doc(), Some("The token"));
+        assert_eq!(desc.lookup("").unwrap().description().name(), "Config");
+
+        assert!(desc.lookup("clusters.*.server").is_none());
+        assert!(desc.lookup("auth.missing").is_none());
+        assert!(desc.lookup("auth[").is_none());
+    }
+}
@@ -0,0 +1,300 @@
+//
+//   This Source Code Form is subject to the terms of the Mozilla Public
+//   License, v. 2.0. If a copy of the MPL was not distributed with this
+//   file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+use std::{cell::RefCell, collections::BTreeMap};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{AsTypeDescription, EnumVariantRepresentation, TypeDescription, TypeKind};
+
+thread_local! {
+    static IN_PROGRESS: RefCell<Vec<&'static str>> = RefCell::new(Vec::new());
+}
+
+struct InProgressGuard;
+
+impl Drop for InProgressGuard {
+    fn drop(&mut self) {
+        IN_PROGRESS.with(|in_progress| in_progress.borrow_mut().pop());
+    }
+}
+
+/// Describe the type `T` while guarding against infinite recursion
+///
+/// If `T` is already being described further up the call stack, a [`TypeKind::Reference`] to
+/// `name` is returned instead of calling `describe` again. This is what makes self-referential
+/// types like `struct Node { children: Vec<Node> }` describable. The
+/// [`derive@TypeDescription`](crate::TypeDescription) derive macro wraps every implementation
+/// in this, manual implementations for recursive types should do the same.
+pub fn describe_recursive<T: ?Sized>(
+    name: &str,
+    describe: impl FnOnce() -> TypeDescription,
+) -> TypeDescription {
+    let key = std::any::type_name::<T>();
+
+    let is_recursive = IN_PROGRESS.with(|in_progress| {
+        let mut in_progress = in_progress.borrow_mut();
+        if in_progress.contains(&key) {
+            true
+        } else {
+            in_progress.push(key);
+            false
+        }
+    });
+
+    if is_recursive {
+        return TypeDescription::reference(name);
+    }
+
+    let _guard = InProgressGuard;
+    describe()
+}
+
+/// A collection of named [`struct@TypeDescription`]s
+///
+/// Every struct, enum, newtype and tuple is stored exactly once under its name, all places where it is used are
+/// replaced by a [`TypeKind::Reference`] to it. Types are identified by their name only, if two
+/// different types share a name the first one registered is kept.
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq, Eq, Clone)]
+pub struct TypeRegistry {
+    types: BTreeMap<String, TypeDescription>,
+}
+
+impl TypeRegistry {
+    /// Create a new, empty [`TypeRegistry`]
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register the type `T` and all named types it contains
+    ///
+    /// Returns the description to use in place of `T`, which is a reference if `T` is a named
+    /// type.
+    pub fn register<T: AsTypeDescription>(&mut self) -> TypeDescription {
+        self.insert(T::as_type_description())
+    }
+
+    /// Register the given description and all named types it contains
+    ///
+    /// Returns the description to use in its place, which is a reference if it is a named type.
+    pub fn insert(&mut self, mut desc: TypeDescription) -> TypeDescription {
+        self.hoist(&mut desc);
+        desc
+    }
+
+    /// Get the named type with the given name
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<&TypeDescription> {
+        self.types.get(name)
+    }
+
+    /// Follow a [`TypeKind::Reference`] to the type it refers to
+    ///
+    /// Other descriptions are returned as they are, `None` is returned if the referenced type is
+    /// not part of this registry.
+    #[must_use]
+    pub fn resolve<'a>(&'a self, desc: &'a TypeDescription) -> Option<&'a TypeDescription> {
+        match desc.kind() {
+            TypeKind::Reference(name) => self.get(name),
+            _ => Some(desc),
+        }
+    }
+
+    /// Iterate over all named types in this registry, ordered by name
+    pub fn iter(&self) -> impl Iterator<Item = &TypeDescription> {
+        self.types.values()
+    }
+
+    /// The number of named types in this registry
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.types.len()
+    }
+
+    /// Whether this registry contains no types
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.types.is_empty()
+    }
+
+    fn hoist(&mut self, desc: &mut TypeDescription) {
+        self.hoist_children(desc);
+
+        if is_named(desc.kind()) {
+            // Constraints belong to this use of the type, not to the type itself
+            let reference = TypeDescription::reference(desc.name())
+                .with_constraints(std::mem::take(&mut desc.constraints));
+            let named = std::mem::replace(desc, reference);
+            self.types.entry(named.name.clone()).or_insert(named);
+        }
+    }
+
+    fn hoist_children(&mut self, desc: &mut TypeDescription) {
+        match &mut desc.kind {
+            TypeKind::Unit
+            | TypeKind::Any
+            | TypeKind::Bool
+            | TypeKind::Char
+            | TypeKind::Bytes { .. }
+            | TypeKind::Integer { .. }
+            | TypeKind::Float { .. }
+            | TypeKind::String
+            | TypeKind::FormattedString(_)
+            | TypeKind::Reference(_) => (),
+            TypeKind::Wrapped(inner)
+            | TypeKind::Optional(inner)
+            | TypeKind::Array(inner)
+            | TypeKind::FixedArray { element: inner, .. } => self.hoist(inner),
+            TypeKind::Tuple(elements) => {
+                for element in elements {
+                    self.hoist(element);
+                }
+            }
+            TypeKind::HashMap { key, value } => {
+                self.hoist(key);
+                self.hoist(value);
+            }
+            TypeKind::Struct(fields) => {
+                for field in fields {
+                    self.hoist(&mut field.kind);
+                }
+            }
+            TypeKind::Enum(_, variants) => {
+                // Variant payloads are named after their variant, only their contents are shared
+                for variant in variants {
+                    if let EnumVariantRepresentation::Wrapped(payload) = &mut variant.repr {
+                        self.hoist_children(payload);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Whether descriptions of this kind are stored under their name
+///
+/// Any type implemented with [`describe_recursive`] can be referred to by name, this covers all
+/// kinds the derive macro produces.
+pub(crate) fn is_named(kind: &TypeKind) -> bool {
+    matches!(
+        kind,
+        TypeKind::Struct(_) | TypeKind::Enum(_, _) | TypeKind::Wrapped(_) | TypeKind::Tuple(_)
+    )
+}
+
+/// Collect the first named type of every name in the given description
+///
+/// This is what consumers of a single description tree use to resolve its
+/// [`TypeKind::Reference`]s.
+pub(crate) fn named_types(desc: &TypeDescription) -> BTreeMap<&str, &TypeDescription> {
+    fn collect<'a>(
+        desc: &'a TypeDescription,
+        is_payload: bool,
+        named: &mut BTreeMap<&'a str, &'a TypeDescription>,
+    ) {
+        if is_named(desc.kind()) && !is_payload {
+            named.entry(desc.name()).or_insert(desc);
+        }
+
+        match desc.kind() {
+            TypeKind::Unit
+            | TypeKind::Any
+            | TypeKind::Bool
+            | TypeKind::Char
+            | TypeKind::Bytes { .. }
+            | TypeKind::Integer { .. }
+            | TypeKind::Float { .. }
+            | TypeKind::String
+            | TypeKind::FormattedString(_)
+            | TypeKind::Reference(_) => (),
+            TypeKind::Wrapped(inner)
+            | TypeKind::Optional(inner)
+            | TypeKind::Array(inner)
+            | TypeKind::FixedArray { element: inner, .. } => collect(inner, false, named),
+            TypeKind::Tuple(elements) => {
+                for element in elements {
+                    collect(element, false, named);
+                }
+            }
+            TypeKind::HashMap { key, value } => {
+                collect(key, false, named);
+                collect(value, false, named);
+            }
+            TypeKind::Struct(fields) => {
+                for field in fields {
+                    collect(field.kind(), false, named);
+                }
+            }
+            TypeKind::Enum(_, variants) => {
+                for variant in variants {
+                    if let EnumVariantRepresentation::Wrapped(payload) = variant.repr() {
+                        collect(payload, true, named);
+                    }
+                }
+            }
+        }
+    }
+
+    let mut named = BTreeMap::new();
+    collect(desc, false, &mut named);
+    named
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{AsTypeDescription, StructField, TypeDescription, TypeKind};
+
+    use super::{describe_recursive, TypeRegistry};
+
+    struct Node;
+
+    impl AsTypeDescription for Node {
+        fn as_type_description() -> TypeDescription {
+            describe_recursive::<Self>("Node", || {
+                TypeDescription::new(
+                    String::from("Node"),
+                    TypeKind::Struct(vec![StructField::new(
+                        "children",
+                        None,
+                        Vec::<Node>::as_type_description(),
+                        false,
+                    )]),
+                    None,
+                )
+            })
+        }
+    }
+
+    #[test]
+    fn recursive_types_terminate() {
+        let desc = Node::as_type_description();
+
+        let TypeKind::Struct(fields) = desc.kind() else {
+            panic!("Expected a struct, got {:?}", desc.kind());
+        };
+        let TypeKind::Array(element) = fields[0].kind().kind() else {
+            panic!("Expected an array, got {:?}", fields[0].kind().kind());
+        };
+        assert_eq!(element.kind(), &TypeKind::Reference(String::from("Node")));
+    }
+
+    #[test]
+    fn registry_stores_types_once() {
+        let mut registry = TypeRegistry::new();
+        let root = registry.register::<Vec<Node>>();
+
+        assert_eq!(registry.len(), 1);
+        assert!(matches!(root.kind(), TypeKind::Array(element) if element.name() == "Node"));
+
+        let node = registry.get("Node").unwrap();
+        assert!(matches!(node.kind(), TypeKind::Struct(_)));
+        assert_eq!(
+            registry.resolve(&TypeDescription::reference("Node")),
+            Some(node)
+        );
+    }
+}
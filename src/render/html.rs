@@ -0,0 +1,465 @@
+//
+//   This Source Code Form is subject to the terms of the Mozilla Public
+//   License, v. 2.0. If a copy of the MPL was not distributed with this
+//   file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Write,
+};
+
+use pulldown_cmark::{escape::escape_html, html::push_html, Event, Parser};
+
+use crate::{
+    visit::{walk, PathSegment, Visitor},
+    BytesEncoding, EnumVariant, EnumVariantRepresentation, StructField, TypeDescription,
+    TypeEnumKind, TypeKind,
+};
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::new();
+    // Writing to a String cannot fail
+    let _ = escape_html(&mut escaped, text);
+    escaped
+}
+
+/// Render the markdown of a doc string, showing any raw HTML in it as text
+///
+/// Descriptions may be built at runtime from untrusted input, so their HTML is never trusted.
+fn markdown_to_html(markdown: &str) -> String {
+    let events = Parser::new(markdown).map(|event| match event {
+        Event::Html(html) => Event::Text(html),
+        event => event,
+    });
+
+    let mut html = String::new();
+    push_html(&mut html, events);
+    html
+}
+
+/// Turn a type name into something usable as an HTML id
+fn slug(name: &str) -> String {
+    let slug = name
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_lowercase()
+            } else {
+                '-'
+            }
+        })
+        .collect::<String>();
+
+    let slug = slug
+        .split('-')
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("-");
+    if slug.is_empty() {
+        String::from("type")
+    } else {
+        slug
+    }
+}
+
+/// The type shown for a field, optional fields already have a badge saying so
+fn field_type(field: &StructField) -> &TypeDescription {
+    match field.kind().kind() {
+        TypeKind::Optional(inner) if field.optional() => inner,
+        _ => field.kind(),
+    }
+}
+
+/// Collects every type that gets its own section, in the order they first appear
+///
+/// Enum variant payloads are rendered as part of their variant instead.
+#[derive(Default)]
+struct Sections<'a> {
+    types: Vec<&'a TypeDescription>,
+    seen: HashSet<&'a TypeDescription>,
+    payload: Option<&'a TypeDescription>,
+}
+
+impl<'a> Visitor<'a> for Sections<'a> {
+    fn enter(&mut self, _path: &[PathSegment], desc: &'a TypeDescription) -> bool {
+        let is_payload = self
+            .payload
+            .take()
+            .map_or(false, |payload| std::ptr::eq(payload, desc));
+        if matches!(desc.kind(), TypeKind::Reference(_)) {
+            return false;
+        }
+        // Optional types are named where they are used instead
+        if is_payload || matches!(desc.kind(), TypeKind::Optional(_)) {
+            return true;
+        }
+        if !self.seen.insert(desc) {
+            return false;
+        }
+
+        self.types.push(desc);
+        true
+    }
+
+    fn visit_variant(&mut self, _path: &[PathSegment], variant: &'a EnumVariant) {
+        if let EnumVariantRepresentation::Wrapped(payload) = variant.repr() {
+            self.payload = Some(payload);
+        }
+    }
+}
+
+struct HtmlRenderer<'a> {
+    ids: HashMap<&'a TypeDescription, String>,
+    /// The sections of structs and enums by name, to link references to
+    named: HashMap<&'a str, String>,
+}
+
+impl<'a> HtmlRenderer<'a> {
+    fn new(types: &[&'a TypeDescription]) -> Self {
+        let mut ids = HashMap::new();
+        let mut named = HashMap::new();
+        let mut used = HashSet::new();
+
+        for desc in types {
+            let base = slug(desc.name());
+            let mut id = base.clone();
+            let mut counter = 1;
+            while !used.insert(id.clone()) {
+                counter += 1;
+                id = format!("{base}-{counter}");
+            }
+
+            if matches!(desc.kind(), TypeKind::Struct(_) | TypeKind::Enum(..)) {
+                named.entry(desc.name()).or_insert_with(|| id.clone());
+            }
+            ids.insert(*desc, id);
+        }
+
+        Self { ids, named }
+    }
+
+    /// The name of a type, linked to its section if it has one
+    fn link(&self, desc: &TypeDescription) -> String {
+        if let TypeKind::Optional(inner) = desc.kind() {
+            return format!("optional {}", self.link(inner));
+        }
+
+        let id = match desc.kind() {
+            TypeKind::Reference(name) => self.named.get(name.as_str()),
+            _ => self.ids.get(desc),
+        };
+
+        match id {
+            Some(id) => format!(r##"<a href="#{id}">{}</a>"##, escape(desc.name())),
+            None => escape(desc.name()),
+        }
+    }
+
+    fn write_fields(&self, html: &mut String, fields: &[StructField]) -> std::fmt::Result {
+        writeln!(html, r#"<ul class="fields">"#)?;
+        for field in fields {
+            write!(
+                html,
+                "<li><code>{}</code> ({})",
+                escape(field.name()),
+                self.link(field_type(field))
+            )?;
+            if field.optional() {
+                write!(html, r#" <span class="badge optional">optional</span>"#)?;
+            } else {
+                write!(html, r#" <span class="badge required">required</span>"#)?;
+            }
+            #[cfg(feature = "serde_json")]
+            if let Some(default) = field.default_value() {
+                write!(
+                    html,
+                    r#" <span class="default">default: <code>{}</code></span>"#,
+                    escape(&default.to_string())
+                )?;
+            }
+            if !field.kind().constraints().is_empty() {
+                write!(
+                    html,
+                    r#" <span class="constraints">{}</span>"#,
+                    escape(&field.kind().constraints().to_string())
+                )?;
+            }
+            writeln!(html)?;
+            if let Some(doc) = field.doc() {
+                write!(html, "{}", markdown_to_html(doc))?;
+            }
+            writeln!(html, "</li>")?;
+        }
+        writeln!(html, "</ul>")
+    }
+
+    fn write_variant(&self, html: &mut String, variant: &EnumVariant) -> std::fmt::Result {
+        write!(
+            html,
+            r#"<details class="variant"><summary><code>{}</code>"#,
+            escape(variant.name())
+        )?;
+
+        let payload = match variant.repr() {
+            EnumVariantRepresentation::String(_) => None,
+            EnumVariantRepresentation::Wrapped(payload) => Some(payload),
+        };
+        // Newtype variants wrap their content in a type named after the variant
+        let content = payload.map(|payload| match payload.kind() {
+            TypeKind::Wrapped(inner) => inner.as_ref(),
+            _ => payload.as_ref(),
+        });
+        if let Some(content) =
+            content.filter(|content| !matches!(content.kind(), TypeKind::Struct(_)))
+        {
+            write!(html, " ({})", self.link(content))?;
+        }
+        writeln!(html, "</summary>")?;
+
+        if let Some(doc) = variant.doc() {
+            write!(html, "{}", markdown_to_html(doc))?;
+        }
+        if let Some(TypeKind::Struct(fields)) = content.map(TypeDescription::kind) {
+            self.write_fields(html, fields)?;
+        }
+        writeln!(html, "</details>")
+    }
+
+    fn write_section(&self, html: &mut String, desc: &TypeDescription) -> std::fmt::Result {
+        writeln!(
+            html,
+            r#"<section class="type" id="{}">"#,
+            self.ids.get(desc).map_or("", String::as_str)
+        )?;
+        writeln!(html, "<h2>{}</h2>", escape(desc.name()))?;
+        if let Some(doc) = desc.doc() {
+            write!(html, "{}", markdown_to_html(doc))?;
+        }
+
+        // Other types share their section between differently constrained uses, so their
+        // constraints are listed with the fields using them instead
+        let is_user_type = matches!(
+            desc.kind(),
+            TypeKind::Wrapped(_) | TypeKind::Struct(_) | TypeKind::Enum(..)
+        );
+        if is_user_type && !desc.constraints().is_empty() {
+            writeln!(
+                html,
+                r#"<p class="constraints">Constraints: {}</p>"#,
+                escape(&desc.constraints().to_string())
+            )?;
+        }
+
+        match desc.kind() {
+            TypeKind::Unit
+            | TypeKind::Bool
+            | TypeKind::Char
+            | TypeKind::Bytes {
+                encoding: BytesEncoding::Raw,
+            }
+            | TypeKind::Integer { .. }
+            | TypeKind::Float { .. }
+            | TypeKind::String
+            | TypeKind::Reference(_) => (),
+            TypeKind::Any => writeln!(html, "<p><em>Accepts any value</em></p>")?,
+            TypeKind::Bytes { encoding } => {
+                writeln!(html, "<p><em>Encoded as {encoding}</em></p>")?
+            }
+            TypeKind::FormattedString(format) => {
+                writeln!(html, "<p><em>Formatted as {format}</em></p>")?
+            }
+            TypeKind::Wrapped(inner) => {
+                writeln!(html, "<p><em>Represented by {}</em></p>", self.link(inner))?
+            }
+            TypeKind::Optional(inner) => {
+                writeln!(html, "<p><em>Optional {}</em></p>", self.link(inner))?
+            }
+            TypeKind::Array(element) => writeln!(
+                html,
+                "<p><em>Array elements of {}</em></p>",
+                self.link(element)
+            )?,
+            TypeKind::FixedArray { element, length } => writeln!(
+                html,
+                "<p><em>Array of exactly {length} elements of {}</em></p>",
+                self.link(element)
+            )?,
+            TypeKind::Tuple(elements) => writeln!(
+                html,
+                "<p><em>Tuple of {}</em></p>",
+                elements
+                    .iter()
+                    .map(|element| self.link(element))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )?,
+            TypeKind::HashMap { key, value } => writeln!(
+                html,
+                "<p><em>Key: {}, Values: {}</em></p>",
+                self.link(key),
+                self.link(value)
+            )?,
+            TypeKind::Struct(fields) => {
+                writeln!(html, "<h3>Fields</h3>")?;
+                self.write_fields(html, fields)?;
+            }
+            TypeKind::Enum(enum_kind, variants) => {
+                let tagging = match enum_kind {
+                    TypeEnumKind::External => String::from("externally tagged"),
+                    TypeEnumKind::Tagged(tag) => {
+                        format!("tagged with field <code>{}</code>", escape(tag))
+                    }
+                    TypeEnumKind::Adjacent { tag, content } => format!(
+                        "tagged with field <code>{}</code>, content in field <code>{}</code>",
+                        escape(tag),
+                        escape(content)
+                    ),
+                    TypeEnumKind::Untagged => String::from("untagged"),
+                };
+                writeln!(html, "<h3>Variants</h3>")?;
+                writeln!(html, "<p><em>The variants are {tagging}</em></p>")?;
+                for variant in variants {
+                    self.write_variant(html, variant)?;
+                }
+            }
+        }
+
+        writeln!(html, "</section>")
+    }
+}
+
+/// Render a [`struct@TypeDescription`] to an HTML fragment
+///
+/// Every type gets its own `<section>`, in the same order as with
+/// [`render_to_markdown`](super::render_to_markdown), and types used by fields and variants link
+/// to their sections. Documentation is converted from Markdown and enum variants can be
+/// collapsed. Use [`render_to_html_page`] for a complete document.
+///
+/// # Example
+///
+/// ```rust
+///     use type_description::{AsTypeDescription, TypeDescription};
+///     use type_description::render::render_to_html;
+///
+///     let desc = TypeDescription::builder("Config")
+///         .documented_field("port", "The **port** to listen on", u16::as_type_description())
+///         .build();
+///     let html = render_to_html(&desc).unwrap();
+///
+///     assert!(html.contains(r##"<code>port</code> (<a href="#integer">Integer</a>)"##));
+///     assert!(html.contains("<p>The <strong>port</strong> to listen on</p>"));
+/// ```
+pub fn render_to_html(desc: &TypeDescription) -> Result<String, std::fmt::Error> {
+    let mut sections = Sections::default();
+    walk(desc, &mut sections);
+
+    let renderer = HtmlRenderer::new(&sections.types);
+    let mut html = String::new();
+    for desc in &sections.types {
+        renderer.write_section(&mut html, desc)?;
+    }
+    Ok(html)
+}
+
+/// Render a [`struct@TypeDescription`] to a self-contained HTML page
+///
+/// This wraps [`render_to_html`] in a document with a title and some basic styling.
+pub fn render_to_html_page(desc: &TypeDescription) -> Result<String, std::fmt::Error> {
+    let mut html = String::new();
+    writeln!(html, "<!DOCTYPE html>")?;
+    writeln!(html, r#"<html lang="en">"#)?;
+    writeln!(html, "<head>")?;
+    writeln!(html, r#"<meta charset="utf-8">"#)?;
+    writeln!(html, "<title>{}</title>", escape(desc.name()))?;
+    writeln!(html, "<style>")?;
+    writeln!(
+        html,
+        "body {{ font-family: sans-serif; max-width: 60em; margin: auto; padding: 1em; }}"
+    )?;
+    writeln!(html, "section.type {{ border-bottom: 1px solid #ccc; }}")?;
+    writeln!(
+        html,
+        ".badge {{ font-size: 0.8em; padding: 0 0.4em; border-radius: 0.4em; }}"
+    )?;
+    writeln!(html, ".badge.required {{ background: #fdd; }}")?;
+    writeln!(html, ".badge.optional {{ background: #ddf; }}")?;
+    writeln!(html, ".constraints, .default {{ color: #666; }}")?;
+    writeln!(html, "details.variant {{ margin: 0.5em 0; }}")?;
+    writeln!(html, "</style>")?;
+    writeln!(html, "</head>")?;
+    writeln!(html, "<body>")?;
+    write!(html, "{}", render_to_html(desc)?)?;
+    writeln!(html, "</body>")?;
+    writeln!(html, "</html>")?;
+    Ok(html)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{AsTypeDescription, EnumVariant, EnumVariantRepresentation, TypeDescription};
+
+    use super::{render_to_html, slug};
+
+    #[test]
+    fn slugs() {
+        assert_eq!(slug("Array of 'Cluster's"), "array-of-cluster-s");
+        assert_eq!(slug("<>"), "type");
+    }
+
+    #[test]
+    fn render_links_and_variants() {
+        let cluster = TypeDescription::builder("Cluster")
+            .optional_field("server", String::as_type_description())
+            .build();
+        let desc = TypeDescription::builder("Config")
+            .field("clusters", TypeDescription::array_of(cluster))
+            .field(
+                "auth",
+                TypeDescription::enum_builder("Auth")
+                    .push_variant(EnumVariant::new(
+                        "Anonymous",
+                        Some("No *authentication*"),
+                        EnumVariantRepresentation::String("Anonymous".into()),
+                    ))
+                    .variant("Token", TypeDescription::reference("Cluster"))
+                    .build(),
+            )
+            .build();
+
+        let html = render_to_html(&desc).unwrap();
+
+        assert_eq!(
+            html.matches("<section").count(),
+            // Config, the array, Cluster, String and Auth, but not the variant payload
+            5
+        );
+        assert!(html.contains(
+            r##"<li><code>clusters</code> (<a href="#array-of-cluster-s">Array of 'Cluster's</a>) <span class="badge required">required</span>"##
+        ));
+        assert!(html.contains(
+            r##"<li><code>server</code> (<a href="#string">String</a>) <span class="badge optional">optional</span>"##
+        ));
+        assert!(html.contains(
+            "<details class=\"variant\"><summary><code>Anonymous</code></summary>\n<p>No <em>authentication</em></p>\n</details>"
+        ));
+        assert!(html.contains(
+            r##"<summary><code>Token</code> (<a href="#cluster">Cluster</a>)</summary>"##
+        ));
+    }
+
+    #[test]
+    fn escape_raw_html_in_docs() {
+        let desc = TypeDescription::builder("Config")
+            .doc("Set <script>alert(1)</script> up\n\n<div onclick=\"steal()\">here</div>")
+            .field("port", u16::as_type_description())
+            .build();
+
+        let html = render_to_html(&desc).unwrap();
+
+        assert!(!html.contains("<script>"));
+        assert!(!html.contains("<div onclick"));
+        assert!(html.contains("&lt;script&gt;alert(1)&lt;/script&gt;"));
+        assert!(html.contains("&lt;div onclick=&quot;steal()&quot;&gt;here&lt;/div&gt;"));
+    }
+}
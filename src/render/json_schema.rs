@@ -0,0 +1,717 @@
+//
+//   This Source Code Form is subject to the terms of the Mozilla Public
+//   License, v. 2.0. If a copy of the MPL was not distributed with this
+//   file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use serde_json::{json, Map, Value};
+
+use crate::{
+    registry::{is_named, named_types},
+    BytesEncoding, EnumVariantRepresentation, Sign, StringFormat, TypeDescription, TypeEnumKind,
+    TypeKind,
+};
+
+const JSON_SCHEMA_DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+/// The smallest and largest value of an integer, leaving out those that are no JSON numbers
+fn integer_bounds(sign: &Sign, size: u8) -> (Option<Value>, Option<Value>) {
+    let size = u32::from(size);
+    match sign {
+        Sign::Signed => match size.checked_sub(1) {
+            None => (Some(json!(0)), Some(json!(0))),
+            Some(bits) => {
+                let magnitude = 1u128.checked_shl(bits);
+                let min = magnitude
+                    .and_then(|magnitude| i128::try_from(magnitude).ok())
+                    .and_then(|magnitude| i64::try_from(-magnitude).ok());
+                let max = magnitude.and_then(|magnitude| u64::try_from(magnitude - 1).ok());
+                (min.map(Value::from), max.map(Value::from))
+            }
+        },
+        Sign::Unsigned => {
+            let max = 1u128
+                .checked_shl(size)
+                .and_then(|magnitude| u64::try_from(magnitude - 1).ok());
+            (Some(json!(0)), max.map(Value::from))
+        }
+    }
+}
+
+/// The name of the given format in JSON schema, if it is one of the formats it defines
+fn format_name(format: StringFormat) -> Option<&'static str> {
+    match format {
+        StringFormat::Ipv4 => Some("ipv4"),
+        StringFormat::Ipv6 => Some("ipv6"),
+        StringFormat::Url => Some("uri"),
+        StringFormat::Uuid => Some("uuid"),
+        StringFormat::DateTime => Some("date-time"),
+        StringFormat::SocketAddr
+        | StringFormat::Ip
+        | StringFormat::Path
+        | StringFormat::ByteSize
+        | StringFormat::Duration => None,
+    }
+}
+
+fn with_description(mut schema: Value, doc: Option<&str>) -> Value {
+    if let (Some(doc), Value::Object(map)) = (doc, &mut schema) {
+        map.insert(String::from("description"), Value::from(doc));
+    }
+    schema
+}
+
+fn definition_reference(name: &str) -> Value {
+    json!({ "$ref": format!("#/$defs/{name}") })
+}
+
+/// Renders schemas, placing every type that is referenced by a [`TypeKind::Reference`] into
+/// `$defs`
+struct SchemaRenderer<'a> {
+    definitions: BTreeMap<&'a str, &'a TypeDescription>,
+}
+
+impl<'a> SchemaRenderer<'a> {
+    fn new(desc: &'a TypeDescription) -> Self {
+        fn collect_references<'a>(desc: &'a TypeDescription, names: &mut BTreeSet<&'a str>) {
+            match desc.kind() {
+                TypeKind::Unit
+                | TypeKind::Any
+                | TypeKind::Bool
+                | TypeKind::Char
+                | TypeKind::Bytes { .. }
+                | TypeKind::Integer { .. }
+                | TypeKind::Float { .. }
+                | TypeKind::String
+                | TypeKind::FormattedString(_) => (),
+                TypeKind::Reference(name) => {
+                    names.insert(name);
+                }
+                TypeKind::Wrapped(inner)
+                | TypeKind::Optional(inner)
+                | TypeKind::Array(inner)
+                | TypeKind::FixedArray { element: inner, .. } => collect_references(inner, names),
+                TypeKind::Tuple(elements) => {
+                    for element in elements {
+                        collect_references(element, names);
+                    }
+                }
+                TypeKind::HashMap { key, value } => {
+                    collect_references(key, names);
+                    collect_references(value, names);
+                }
+                TypeKind::Struct(fields) => {
+                    for field in fields {
+                        collect_references(field.kind(), names);
+                    }
+                }
+                TypeKind::Enum(_, variants) => {
+                    for variant in variants {
+                        if let EnumVariantRepresentation::Wrapped(payload) = variant.repr() {
+                            collect_references(payload, names);
+                        }
+                    }
+                }
+            }
+        }
+
+        let mut referenced = BTreeSet::new();
+        collect_references(desc, &mut referenced);
+
+        let definitions = named_types(desc)
+            .into_iter()
+            .filter(|(name, _)| referenced.contains(name))
+            .collect();
+
+        SchemaRenderer { definitions }
+    }
+
+    fn variant_schema(
+        &self,
+        enum_kind: &TypeEnumKind,
+        name: &str,
+        repr: &EnumVariantRepresentation,
+    ) -> Value {
+        match enum_kind {
+            TypeEnumKind::Tagged(tag) => {
+                let tag_schema = json!({
+                    "type": "object",
+                    "properties": {
+                        tag.as_ref(): { "const": name }
+                    },
+                    "required": [tag.as_ref()],
+                });
+
+                match repr {
+                    EnumVariantRepresentation::String(_) => tag_schema,
+                    EnumVariantRepresentation::Wrapped(wrapped) => json!({
+                        "allOf": [tag_schema, self.payload_schema(wrapped)]
+                    }),
+                }
+            }
+            TypeEnumKind::External => match repr {
+                EnumVariantRepresentation::String(_) => json!({ "const": name }),
+                EnumVariantRepresentation::Wrapped(wrapped) => json!({
+                    "type": "object",
+                    "properties": {
+                        name: self.payload_schema(wrapped)
+                    },
+                    "required": [name],
+                    "additionalProperties": false,
+                }),
+            },
+            TypeEnumKind::Adjacent { tag, content } => match repr {
+                EnumVariantRepresentation::String(_) => json!({
+                    "type": "object",
+                    "properties": {
+                        tag.as_ref(): { "const": name }
+                    },
+                    "required": [tag.as_ref()],
+                }),
+                EnumVariantRepresentation::Wrapped(wrapped) => json!({
+                    "type": "object",
+                    "properties": {
+                        tag.as_ref(): { "const": name },
+                        content.as_ref(): self.payload_schema(wrapped),
+                    },
+                    "required": [tag.as_ref(), content.as_ref()],
+                }),
+            },
+            TypeEnumKind::Untagged => match repr {
+                // Like serde, untagged unit variants are written as `null` instead of their name
+                EnumVariantRepresentation::String(_) => json!({ "type": "null" }),
+                EnumVariantRepresentation::Wrapped(wrapped) => self.payload_schema(wrapped),
+            },
+        }
+    }
+
+    /// Variant payloads are named after their variant, so a definition sharing that name is a
+    /// different type
+    fn payload_schema(&self, payload: &TypeDescription) -> Value {
+        self.schema_body(payload)
+    }
+
+    fn schema_of(&self, desc: &TypeDescription) -> Value {
+        let is_definition = is_named(desc.kind()) && self.definitions.contains_key(desc.name());
+
+        if is_definition {
+            return self.with_constraints(definition_reference(desc.name()), desc);
+        }
+
+        self.schema_body(desc)
+    }
+
+    /// The keywords limiting the length of values of this type, if it has one
+    fn length_keywords(&self, desc: &TypeDescription) -> Option<(&'static str, &'static str)> {
+        match desc.kind() {
+            TypeKind::String | TypeKind::FormattedString(_) => Some(("minLength", "maxLength")),
+            TypeKind::Array(_) | TypeKind::FixedArray { .. } | TypeKind::Tuple(_) => {
+                Some(("minItems", "maxItems"))
+            }
+            TypeKind::HashMap { .. } | TypeKind::Struct(_) => {
+                Some(("minProperties", "maxProperties"))
+            }
+            TypeKind::Wrapped(inner) | TypeKind::Optional(inner) => self.length_keywords(inner),
+            TypeKind::Reference(name) => self
+                .definitions
+                .get(name.as_str())
+                .and_then(|definition| self.length_keywords(definition)),
+            TypeKind::Unit
+            | TypeKind::Any
+            | TypeKind::Bool
+            | TypeKind::Char
+            | TypeKind::Bytes { .. }
+            | TypeKind::Integer { .. }
+            | TypeKind::Float { .. }
+            | TypeKind::Enum(..) => None,
+        }
+    }
+
+    fn with_constraints(&self, mut schema: Value, desc: &TypeDescription) -> Value {
+        let constraints = desc.constraints();
+        let Value::Object(map) = &mut schema else {
+            return schema;
+        };
+
+        if let Some(min) = constraints.min() {
+            map.insert(String::from("minimum"), Value::from(min.clone()));
+        }
+        if let Some(max) = constraints.max() {
+            map.insert(String::from("maximum"), Value::from(max.clone()));
+        }
+        if let Some((min_keyword, max_keyword)) = self.length_keywords(desc) {
+            if let Some(min_len) = constraints.min_len() {
+                map.insert(String::from(min_keyword), Value::from(min_len));
+            }
+            if let Some(max_len) = constraints.max_len() {
+                map.insert(String::from(max_keyword), Value::from(max_len));
+            }
+        }
+        if let Some(pattern) = constraints.pattern() {
+            map.insert(String::from("pattern"), Value::from(pattern));
+        }
+
+        schema
+    }
+
+    fn schema_body(&self, desc: &TypeDescription) -> Value {
+        let schema = match desc.kind() {
+            TypeKind::Unit => json!({ "type": "null" }),
+            // The empty schema accepts every value
+            TypeKind::Any => json!({}),
+            TypeKind::Bool => json!({ "type": "boolean" }),
+            TypeKind::Char => json!({ "type": "string", "minLength": 1, "maxLength": 1 }),
+            TypeKind::Bytes {
+                encoding: BytesEncoding::Raw,
+            } => json!({
+                "type": "array",
+                "items": { "type": "integer", "minimum": 0, "maximum": 255 },
+            }),
+            TypeKind::Bytes {
+                encoding: BytesEncoding::Base64,
+            } => json!({ "type": "string", "contentEncoding": "base64" }),
+            TypeKind::Bytes {
+                encoding: BytesEncoding::Hex,
+            } => json!({ "type": "string", "contentEncoding": "base16" }),
+            TypeKind::Integer { sign, size } => {
+                let (minimum, maximum) = integer_bounds(sign, *size);
+                let mut schema = json!({ "type": "integer" });
+                if let Some(minimum) = minimum {
+                    schema["minimum"] = minimum;
+                }
+                if let Some(maximum) = maximum {
+                    schema["maximum"] = maximum;
+                }
+                schema
+            }
+            TypeKind::Float { .. } => json!({ "type": "number" }),
+            TypeKind::Reference(name) => definition_reference(name),
+            TypeKind::String => json!({ "type": "string" }),
+            TypeKind::FormattedString(format) => match format_name(*format) {
+                Some(format) => json!({ "type": "string", "format": format }),
+                None => json!({ "type": "string" }),
+            },
+            TypeKind::Wrapped(wrapped) => self.schema_of(wrapped),
+            TypeKind::Optional(inner) => json!({
+                "anyOf": [self.schema_of(inner), { "type": "null" }],
+            }),
+            TypeKind::Array(arr) => json!({
+                "type": "array",
+                "items": self.schema_of(arr),
+            }),
+            TypeKind::FixedArray { element, length } => json!({
+                "type": "array",
+                "items": self.schema_of(element),
+                "minItems": length,
+                "maxItems": length,
+            }),
+            TypeKind::Tuple(elements) => json!({
+                "type": "array",
+                "prefixItems": elements.iter().map(|element| self.schema_of(element)).collect::<Vec<_>>(),
+                "items": false,
+                "minItems": elements.len(),
+            }),
+            TypeKind::HashMap { key: _, value } => json!({
+                "type": "object",
+                "additionalProperties": self.schema_of(value),
+            }),
+            TypeKind::Struct(fields) => {
+                let properties = fields
+                    .iter()
+                    .map(|field| {
+                        let mut schema =
+                            with_description(self.schema_of(field.kind()), field.doc());
+                        if let (Some(default), Value::Object(map)) =
+                            (field.default_value(), &mut schema)
+                        {
+                            map.insert(String::from("default"), default.value().clone());
+                        }
+                        (field.name().to_string(), schema)
+                    })
+                    .collect::<Map<_, _>>();
+
+                let required = fields
+                    .iter()
+                    .filter(|field| !field.optional())
+                    .map(|field| Value::from(field.name()))
+                    .collect::<Vec<_>>();
+
+                json!({
+                    "type": "object",
+                    "properties": properties,
+                    "required": required,
+                })
+            }
+            TypeKind::Enum(enum_kind, variants) => {
+                let variants = variants
+                    .iter()
+                    .map(|variant| {
+                        with_description(
+                            self.variant_schema(enum_kind, variant.name(), variant.repr()),
+                            variant.doc(),
+                        )
+                    })
+                    .collect::<Vec<_>>();
+
+                match enum_kind {
+                    TypeEnumKind::Untagged => json!({ "anyOf": variants }),
+                    TypeEnumKind::External
+                    | TypeEnumKind::Tagged(_)
+                    | TypeEnumKind::Adjacent { .. } => json!({ "oneOf": variants }),
+                }
+            }
+        };
+
+        self.with_constraints(with_description(schema, desc.doc()), desc)
+    }
+}
+
+/// Render a [`struct@TypeDescription`] to a JSON Schema (draft 2020-12) document
+///
+/// Struct fields which are not [optional](crate::StructField::optional) are listed as
+/// `required`, tagged enums become a `oneOf` with a `const` discriminator for the variant names
+/// and all docs are emitted as `description`s. Recursive types are placed into `$defs`.
+///
+/// # Example
+///
+/// ```rust
+///     use type_description::AsTypeDescription;
+///     use type_description::render::render_to_json_schema;
+///
+///     let ty_desc = std::collections::HashMap::<String, u16>::as_type_description();
+///     let schema = render_to_json_schema(&ty_desc);
+///
+///     assert_eq!(schema["type"], "object");
+///     assert_eq!(schema["additionalProperties"]["maximum"], 65535);
+/// ```
+pub fn render_to_json_schema(desc: &TypeDescription) -> Value {
+    let renderer = SchemaRenderer::new(desc);
+    let mut schema = renderer.schema_of(desc);
+
+    if let Value::Object(map) = &mut schema {
+        map.insert(String::from("$schema"), Value::from(JSON_SCHEMA_DIALECT));
+        map.insert(String::from("title"), Value::from(desc.name()));
+
+        if !renderer.definitions.is_empty() {
+            let definitions = renderer
+                .definitions
+                .iter()
+                .map(|(name, definition)| (name.to_string(), renderer.schema_body(definition)))
+                .collect::<Map<_, _>>();
+            map.insert(String::from("$defs"), Value::Object(definitions));
+        }
+    }
+
+    schema
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use serde_json::json;
+
+    use crate::{
+        AsTypeDescription, BytesEncoding, Constraints, EnumVariant, EnumVariantRepresentation,
+        Sign, StructField, TypeDescription, TypeEnumKind, TypeKind,
+    };
+
+    use super::render_to_json_schema;
+
+    #[test]
+    fn render_optional() {
+        let schema = render_to_json_schema(&Option::<bool>::as_type_description());
+
+        assert_eq!(
+            schema["anyOf"],
+            json!([
+                { "type": "boolean", "description": "A boolean" },
+                { "type": "null" },
+            ])
+        );
+    }
+
+    #[test]
+    fn render_bytes() {
+        let schema = render_to_json_schema(&TypeDescription::bytes(BytesEncoding::Hex));
+        assert_eq!(schema["type"], "string");
+        assert_eq!(schema["contentEncoding"], "base16");
+
+        let schema = render_to_json_schema(&TypeDescription::bytes(BytesEncoding::Raw));
+        assert_eq!(schema["type"], "array");
+        assert_eq!(schema["items"]["maximum"], 255);
+    }
+
+    #[test]
+    fn render_struct() {
+        let desc = TypeDescription::new(
+            String::from("Config"),
+            TypeKind::Struct(vec![
+                StructField::new("port", Some("The port"), u16::as_type_description(), false),
+                StructField::new("offset", None, i8::as_type_description(), true),
+            ]),
+            Some("A config"),
+        );
+
+        let schema = render_to_json_schema(&desc);
+
+        assert_eq!(schema["title"], "Config");
+        assert_eq!(schema["description"], "A config");
+        assert_eq!(schema["required"], json!(["port"]));
+        assert_eq!(schema["properties"]["port"]["description"], "The port");
+        assert_eq!(schema["properties"]["port"]["maximum"], 65535);
+        assert_eq!(schema["properties"]["offset"]["minimum"], -128);
+        assert_eq!(schema["properties"]["offset"]["maximum"], 127);
+    }
+
+    #[test]
+    fn render_tagged_enum() {
+        let desc = TypeDescription::new(
+            String::from("Auth"),
+            TypeKind::Enum(
+                TypeEnumKind::Tagged(Cow::Borrowed("type")),
+                vec![EnumVariant::new(
+                    "None",
+                    None,
+                    EnumVariantRepresentation::String(Cow::Borrowed("None")),
+                )],
+            ),
+            None,
+        );
+
+        let schema = render_to_json_schema(&desc);
+
+        assert_eq!(
+            schema["oneOf"][0]["properties"]["type"],
+            json!({ "const": "None" })
+        );
+        assert_eq!(schema["oneOf"][0]["required"], json!(["type"]));
+    }
+
+    #[test]
+    fn render_externally_tagged_enum() {
+        let desc = TypeDescription::new(
+            String::from("Auth"),
+            TypeKind::Enum(
+                TypeEnumKind::External,
+                vec![
+                    EnumVariant::new(
+                        "None",
+                        None,
+                        EnumVariantRepresentation::String(Cow::Borrowed("None")),
+                    ),
+                    EnumVariant::new(
+                        "Token",
+                        None,
+                        EnumVariantRepresentation::Wrapped(Box::new(String::as_type_description())),
+                    ),
+                ],
+            ),
+            None,
+        );
+
+        let schema = render_to_json_schema(&desc);
+
+        assert_eq!(schema["oneOf"][0], json!({ "const": "None" }));
+        assert_eq!(
+            schema["oneOf"][1]["properties"]["Token"]["type"],
+            json!("string")
+        );
+        assert_eq!(schema["oneOf"][1]["required"], json!(["Token"]));
+    }
+
+    #[test]
+    fn render_untagged_enum() {
+        let desc = TypeDescription::new(
+            String::from("Level"),
+            TypeKind::Enum(
+                TypeEnumKind::Untagged,
+                vec![
+                    EnumVariant::new(
+                        "Off",
+                        None,
+                        EnumVariantRepresentation::String(Cow::Borrowed("Off")),
+                    ),
+                    EnumVariant::new(
+                        "Fixed",
+                        None,
+                        EnumVariantRepresentation::Wrapped(Box::new(u8::as_type_description())),
+                    ),
+                ],
+            ),
+            None,
+        );
+
+        let schema = render_to_json_schema(&desc);
+
+        assert_eq!(schema["anyOf"][0], json!({ "type": "null" }));
+        assert_eq!(schema["anyOf"][1]["maximum"], 255);
+    }
+
+    #[test]
+    fn render_integer_bounds() {
+        let integer = |sign, size| {
+            render_to_json_schema(&TypeDescription::new_owned(
+                "Integer",
+                TypeKind::Integer { sign, size },
+                None,
+            ))
+        };
+
+        let schema = integer(Sign::Signed, 64);
+        assert_eq!(schema["minimum"], i64::MIN);
+        assert_eq!(schema["maximum"], i64::MAX);
+
+        let schema = integer(Sign::Unsigned, 0);
+        assert_eq!(schema["maximum"], 0);
+
+        let schema = integer(Sign::Signed, 128);
+        assert_eq!(schema.get("minimum"), None);
+        assert_eq!(schema.get("maximum"), None);
+
+        let schema = integer(Sign::Unsigned, 128);
+        assert_eq!(schema["minimum"], 0);
+        assert_eq!(schema.get("maximum"), None);
+    }
+
+    #[test]
+    fn render_tuple() {
+        let schema = render_to_json_schema(&<(u16, String)>::as_type_description());
+
+        assert_eq!(schema["prefixItems"][0]["maximum"], 65535);
+        assert_eq!(schema["prefixItems"][1]["type"], "string");
+        assert_eq!(schema["items"], false);
+        assert_eq!(schema["minItems"], 2);
+    }
+
+    #[test]
+    fn render_constraints() {
+        let desc = TypeDescription::new(
+            String::from("Config"),
+            TypeKind::Struct(vec![
+                StructField::new(
+                    "port",
+                    None,
+                    u16::as_type_description()
+                        .with_constraints(Constraints::new().with_min(1).with_max(1024)),
+                    false,
+                ),
+                StructField::new(
+                    "names",
+                    None,
+                    Vec::<String>::as_type_description()
+                        .with_constraints(Constraints::new().with_min_len(1)),
+                    false,
+                ),
+                StructField::new(
+                    "host",
+                    None,
+                    String::as_type_description()
+                        .with_constraints(Constraints::new().with_pattern("^[a-z]+$")),
+                    false,
+                ),
+            ]),
+            None,
+        );
+
+        let schema = render_to_json_schema(&desc);
+
+        assert_eq!(schema["properties"]["port"]["minimum"], 1);
+        assert_eq!(schema["properties"]["port"]["maximum"], 1024);
+        assert_eq!(schema["properties"]["names"]["minItems"], 1);
+        assert_eq!(schema["properties"]["host"]["pattern"], "^[a-z]+$");
+    }
+
+    #[test]
+    fn render_string_formats() {
+        let schema = render_to_json_schema(&std::net::Ipv6Addr::as_type_description());
+        assert_eq!(schema["type"], "string");
+        assert_eq!(schema["format"], "ipv6");
+
+        let schema = render_to_json_schema(&std::path::PathBuf::as_type_description());
+        assert_eq!(schema.get("format"), None);
+    }
+
+    #[test]
+    fn render_recursive() {
+        let desc = TypeDescription::new(
+            String::from("Node"),
+            TypeKind::Struct(vec![StructField::new(
+                "children",
+                None,
+                TypeDescription::new(
+                    String::from("Array of 'Node's"),
+                    TypeKind::Array(Box::new(TypeDescription::reference("Node"))),
+                    None,
+                ),
+                false,
+            )]),
+            None,
+        );
+
+        let schema = render_to_json_schema(&desc);
+
+        assert_eq!(schema["$ref"], "#/$defs/Node");
+        assert_eq!(
+            schema["$defs"]["Node"]["properties"]["children"]["items"],
+            json!({ "$ref": "#/$defs/Node" })
+        );
+    }
+
+    #[test]
+    fn render_recursive_newtype() {
+        let tree = TypeDescription::new(
+            String::from("Tree"),
+            TypeKind::Wrapped(Box::new(TypeDescription::new(
+                String::from("Array of 'Tree's"),
+                TypeKind::Array(Box::new(TypeDescription::reference("Tree"))),
+                None,
+            ))),
+            None,
+        );
+        let desc = TypeDescription::builder("Holder")
+            .field("tree", tree)
+            .build();
+
+        let schema = render_to_json_schema(&desc);
+
+        assert_eq!(
+            schema["properties"]["tree"],
+            json!({ "$ref": "#/$defs/Tree" })
+        );
+        assert_eq!(
+            schema["$defs"]["Tree"]["items"],
+            json!({ "$ref": "#/$defs/Tree" })
+        );
+    }
+
+    #[test]
+    fn render_payload_sharing_a_definition_name() {
+        // Like the derive describes `enum Mode { Config { verbose: bool } }`
+        let payload = TypeDescription::builder("Config")
+            .field("verbose", bool::as_type_description())
+            .build();
+        let mode = TypeDescription::enum_builder("Mode")
+            .push_variant(EnumVariant::new(
+                "Config",
+                None,
+                EnumVariantRepresentation::Wrapped(Box::new(payload)),
+            ))
+            .build();
+        let desc = TypeDescription::builder("Config")
+            .field("mode", mode)
+            .optional_field("parent", TypeDescription::reference("Config"))
+            .build();
+
+        let schema = render_to_json_schema(&desc);
+
+        assert_eq!(schema["$ref"], "#/$defs/Config");
+        let variant = &schema["$defs"]["Config"]["properties"]["mode"]["oneOf"][0];
+        assert_eq!(
+            variant["properties"]["Config"]["properties"]["verbose"]["type"],
+            "boolean"
+        );
+    }
+}
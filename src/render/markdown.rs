@@ -4,37 +4,359 @@
 //   file, You can obtain one at http://mozilla.org/MPL/2.0/.
 //
 
+use std::{borrow::Cow, collections::HashMap, fmt::Write};
+
 use indexmap::IndexSet;
 
-use crate::{TypeDescription, TypeEnumKind};
+use crate::{
+    visit::{walk, PathSegment, Visitor},
+    BytesEncoding, EnumVariant, EnumVariantRepresentation, StructField, TypeDescription,
+    TypeEnumKind, TypeKind,
+};
+
+/// How the fields of a struct are listed by [`render_to_markdown_with`]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum FieldStyle {
+    /// A bullet list with one field per item
+    #[default]
+    List,
+    /// A table with one field per row
+    Table,
+}
+
+/// The order of the type sections rendered by [`render_to_markdown_with`]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum TypeOrder {
+    /// In the order the types are first used, starting with the rendered type
+    #[default]
+    Appearance,
+    /// Sorted by name, except for the rendered type which always comes first
+    Name,
+}
+
+/// Options for [`render_to_markdown_with`]
+///
+/// The default options are the ones used by [`render_to_markdown`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MarkdownOptions {
+    heading_level: usize,
+    inline_primitives: bool,
+    field_style: FieldStyle,
+    placeholder: Option<Cow<'static, str>>,
+    order: TypeOrder,
+    table_of_contents: bool,
+    collapse: bool,
+    required_first: bool,
+}
+
+impl Default for MarkdownOptions {
+    fn default() -> Self {
+        Self {
+            heading_level: 1,
+            inline_primitives: false,
+            field_style: FieldStyle::List,
+            placeholder: Some(Cow::Borrowed("_No doc_")),
+            order: TypeOrder::Appearance,
+            table_of_contents: false,
+            collapse: false,
+            required_first: false,
+        }
+    }
+}
+
+impl MarkdownOptions {
+    /// Create the default options
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the level of the heading of every type section, between 1 and 6
+    ///
+    /// This allows embedding the output below existing headings.
+    #[must_use]
+    pub fn with_heading_level(mut self, level: usize) -> Self {
+        self.heading_level = level.clamp(1, 6);
+        self
+    }
+
+    /// Set whether booleans, numbers and strings are only named where they are used
+    ///
+    /// Otherwise they get their own sections like every other type.
+    #[must_use]
+    pub fn with_inline_primitives(mut self, inline_primitives: bool) -> Self {
+        self.inline_primitives = inline_primitives;
+        self
+    }
+
+    /// Set how the fields of structs are listed
+    #[must_use]
+    pub fn with_field_style(mut self, field_style: FieldStyle) -> Self {
+        self.field_style = field_style;
+        self
+    }
+
+    /// Set the text shown for fields and variants without documentation, `None` to show nothing
+    #[must_use]
+    pub fn with_placeholder(mut self, placeholder: Option<Cow<'static, str>>) -> Self {
+        self.placeholder = placeholder;
+        self
+    }
+
+    /// Set the order of the type sections
+    #[must_use]
+    pub fn with_order(mut self, order: TypeOrder) -> Self {
+        self.order = order;
+        self
+    }
+
+    /// Set whether a list of links to every type section comes first
+    #[must_use]
+    pub fn with_table_of_contents(mut self, table_of_contents: bool) -> Self {
+        self.table_of_contents = table_of_contents;
+        self
+    }
+
+    /// Set whether the fields and variants of a type are collapsed into `<details>` elements
+    #[must_use]
+    pub fn with_collapse(mut self, collapse: bool) -> Self {
+        self.collapse = collapse;
+        self
+    }
+
+    /// Set whether required fields are listed before optional ones
+    ///
+    /// Otherwise fields are listed in the order they were declared in.
+    #[must_use]
+    pub fn with_required_first(mut self, required_first: bool) -> Self {
+        self.required_first = required_first;
+        self
+    }
+}
+
+/// Whether a variant payload is rendered as part of its enum instead of in its own section
+///
+/// The derive wraps the content of newtype variants in a type named after the variant, and
+/// describes struct variants as a struct named after the variant. Neither is worth a section.
+fn is_inline_payload(payload: &TypeDescription) -> bool {
+    matches!(payload.kind(), TypeKind::Wrapped(_) | TypeKind::Struct(_))
+}
+
+/// Collects every type that gets its own section, in the order they first appear
+struct ListOfTypes<'a> {
+    types: IndexSet<&'a TypeDescription>,
+    inline_payload: Option<&'a TypeDescription>,
+}
+
+impl<'a> Visitor<'a> for ListOfTypes<'a> {
+    fn enter(&mut self, _path: &[PathSegment], desc: &'a TypeDescription) -> bool {
+        if self
+            .inline_payload
+            .take()
+            .map_or(false, |payload| std::ptr::eq(payload, desc))
+        {
+            return true;
+        }
+        // Optional types are named where they are used instead
+        if matches!(desc.kind(), TypeKind::Optional(_)) {
+            return true;
+        }
+
+        // References point to a type that is already part of the list
+        !matches!(desc.kind(), TypeKind::Reference(_)) && self.types.insert(desc)
+    }
+
+    fn visit_variant(&mut self, _path: &[PathSegment], variant: &'a EnumVariant) {
+        if let EnumVariantRepresentation::Wrapped(payload) = variant.repr() {
+            if is_inline_payload(payload) {
+                self.inline_payload = Some(payload);
+            }
+        }
+    }
+}
 
 fn get_list_of_types(desc: &TypeDescription) -> IndexSet<&TypeDescription> {
-    let mut types = IndexSet::new();
+    let mut list = ListOfTypes {
+        types: IndexSet::new(),
+        inline_payload: None,
+    };
+    walk(desc, &mut list);
+    list.types
+}
 
-    let mut remaining = vec![desc];
+/// The anchors of the sections of the given types, numbered like duplicate headings are
+fn section_anchors(types: &[&TypeDescription]) -> Vec<String> {
+    let mut anchors = HashMap::<String, usize>::new();
+    types
+        .iter()
+        .map(|ty| {
+            let anchor = heading_anchor(ty.name());
+            let count = anchors.entry(anchor.clone()).or_default();
+            *count += 1;
+            match *count {
+                1 => anchor,
+                count => format!("{anchor}-{}", count - 1),
+            }
+        })
+        .collect()
+}
 
-    while let Some(cur) = remaining.pop() {
-        types.insert(cur);
+/// The anchor GitHub and mdBook generate for a heading
+fn heading_anchor(heading: &str) -> String {
+    heading
+        .chars()
+        .filter_map(|c| match c {
+            ' ' => Some('-'),
+            c if c.is_alphanumeric() || c == '-' || c == '_' => Some(c.to_ascii_lowercase()),
+            _ => None,
+        })
+        .collect()
+}
 
-        match cur.kind() {
-            crate::TypeKind::Bool
-            | crate::TypeKind::Integer { .. }
-            | crate::TypeKind::Float { .. }
-            | crate::TypeKind::Enum(_, _)
-            | crate::TypeKind::String => (),
-            crate::TypeKind::Wrapped(wrapped) => remaining.push(wrapped),
-            crate::TypeKind::Array(arr) => remaining.push(arr),
-            crate::TypeKind::HashMap { key, value } => {
-                remaining.push(value);
-                remaining.push(key);
+/// Make text fit into a single table cell
+fn table_cell(text: &str) -> String {
+    text.trim()
+        .replace('|', "\\|")
+        .lines()
+        .collect::<Vec<_>>()
+        .join("<br>")
+}
+
+/// The section a variant's payload is described in, if it has one
+fn payload_section<'a>(
+    payload: &'a TypeDescription,
+    types: &[&TypeDescription],
+) -> Option<(usize, &'a TypeDescription)> {
+    let target = match payload.kind() {
+        TypeKind::Wrapped(inner) => inner,
+        _ => payload,
+    };
+    let position = match target.kind() {
+        TypeKind::Reference(name) => types.iter().position(|ty| ty.name() == name),
+        _ => types.iter().position(|ty| *ty == target),
+    };
+    position.map(|idx| (idx, target))
+}
+
+/// The name of a type where it is used, optional types do not get their own sections
+fn type_name(desc: &TypeDescription) -> Cow<'_, str> {
+    match desc.kind() {
+        TypeKind::Optional(inner) => Cow::Owned(format!("optional {}", type_name(inner))),
+        _ => Cow::Borrowed(desc.name()),
+    }
+}
+
+/// Whether a field must be set, as written next to it
+fn requirement(field: &StructField) -> &'static str {
+    if field.optional() {
+        "optional"
+    } else {
+        "required"
+    }
+}
+
+/// The fields in the order they are listed in
+fn ordered_fields<'a>(
+    fields: &'a [StructField],
+    options: &MarkdownOptions,
+) -> Vec<&'a StructField> {
+    let mut fields = fields.iter().collect::<Vec<_>>();
+    if options.required_first {
+        fields.sort_by_key(|field| field.optional());
+    }
+    fields
+}
+
+/// The type, default value and constraints of a field
+fn field_summary(field: &StructField) -> String {
+    // Optional fields are already marked as such
+    let ty = match field.kind().kind() {
+        TypeKind::Optional(inner) if field.optional() => inner,
+        _ => field.kind(),
+    };
+    let mut summary = type_name(ty).into_owned();
+    #[cfg(feature = "serde_json")]
+    if let Some(default) = field.default_value() {
+        summary.push_str(&format!(", default: `{default}`"));
+    }
+    if !field.kind().constraints().is_empty() {
+        summary.push_str(&format!(", {}", field.kind().constraints()));
+    }
+    summary
+}
+
+fn write_field_item(
+    markdown: &mut String,
+    indent: &str,
+    field: &StructField,
+    options: &MarkdownOptions,
+) -> std::fmt::Result {
+    write!(
+        markdown,
+        "{indent}- `{}` ({}) _{}_",
+        field.name(),
+        field_summary(field),
+        requirement(field)
+    )?;
+    match field.doc().or(options.placeholder.as_deref()) {
+        Some(doc) => writeln!(markdown, ": {doc}"),
+        None => writeln!(markdown),
+    }
+}
+
+fn write_fields(
+    markdown: &mut String,
+    fields: &[StructField],
+    options: &MarkdownOptions,
+) -> std::fmt::Result {
+    match options.field_style {
+        FieldStyle::List => {
+            for field in ordered_fields(fields, options) {
+                write_field_item(markdown, "", field, options)?;
             }
-            crate::TypeKind::Struct(strt) => {
-                remaining.extend(strt.iter().rev().map(|field| &field.kind));
+        }
+        FieldStyle::Table => {
+            writeln!(markdown, "| Field | Type | Required | Description |")?;
+            writeln!(markdown, "| --- | --- | --- | --- |")?;
+            for field in ordered_fields(fields, options) {
+                writeln!(
+                    markdown,
+                    "| `{}` | {} | {} | {} |",
+                    field.name(),
+                    table_cell(&field_summary(field)),
+                    if field.optional() { "No" } else { "Yes" },
+                    table_cell(field.doc().or(options.placeholder.as_deref()).unwrap_or(""))
+                )?;
             }
         }
     }
+    Ok(())
+}
 
-    types
+/// Write the heading of a list of fields or variants
+fn write_list_start(
+    markdown: &mut String,
+    title: &str,
+    detail: Option<&str>,
+    options: &MarkdownOptions,
+) -> std::fmt::Result {
+    let detail = detail
+        .map(|detail| format!(" {detail}"))
+        .unwrap_or_default();
+    if options.collapse {
+        writeln!(markdown, "<details>")?;
+        writeln!(markdown, "<summary>{title}{detail}</summary>")?;
+    } else {
+        writeln!(markdown, "**{title}:**{detail}")?;
+    }
+    writeln!(markdown)
+}
+
+fn write_list_end(markdown: &mut String, options: &MarkdownOptions) -> std::fmt::Result {
+    if options.collapse {
+        writeln!(markdown)?;
+        writeln!(markdown, "</details>")?;
+    }
+    Ok(())
 }
 
 /// Render a [`struct@TypeDescription`] to a Markdown string
@@ -68,30 +390,144 @@ fn get_list_of_types(desc: &TypeDescription) -> IndexSet<&TypeDescription> {
 ///
 /// _Array Elements of String_
 pub fn render_to_markdown(desc: &TypeDescription) -> Result<String, std::fmt::Error> {
-    use std::fmt::Write;
+    render_to_markdown_with(desc, &MarkdownOptions::default())
+}
 
-    let list_of_types = get_list_of_types(desc);
+/// Render a [`struct@TypeDescription`] to a Markdown string with the given options
+///
+/// # Example
+///
+/// ```rust
+///     use type_description::{AsTypeDescription, TypeDescription};
+///     use type_description::render::{render_to_markdown_with, FieldStyle, MarkdownOptions};
+///
+///     let desc = TypeDescription::builder("Config")
+///         .field("port", u16::as_type_description())
+///         .build();
+///     let options = MarkdownOptions::new()
+///         .with_heading_level(2)
+///         .with_inline_primitives(true)
+///         .with_field_style(FieldStyle::Table)
+///         .with_placeholder(None);
+///
+///     assert_eq!(
+///         render_to_markdown_with(&desc, &options).unwrap(),
+///         "## Config\n\n\n**Fields:**\n\n| Field | Type | Required | Description |\n| --- | --- | --- | --- |\n| `port` | Integer | Yes |  |\n\n",
+///     );
+/// ```
+pub fn render_to_markdown_with(
+    desc: &TypeDescription,
+    options: &MarkdownOptions,
+) -> Result<String, std::fmt::Error> {
+    let mut list_of_types = get_list_of_types(desc)
+        .into_iter()
+        .filter(|ty| {
+            !options.inline_primitives
+                || !matches!(
+                    ty.kind(),
+                    TypeKind::Unit
+                        | TypeKind::Any
+                        | TypeKind::Bool
+                        | TypeKind::Char
+                        | TypeKind::Bytes { .. }
+                        | TypeKind::Integer { .. }
+                        | TypeKind::Float { .. }
+                        | TypeKind::String
+                        | TypeKind::FormattedString(_)
+                )
+        })
+        .collect::<Vec<_>>();
+    if options.order == TypeOrder::Name && !list_of_types.is_empty() {
+        // The rendered type itself stays first, unless it was inlined
+        let start = usize::from(list_of_types[0] == desc);
+        list_of_types[start..].sort_by_key(|ty| ty.name());
+    }
 
+    let heading = "#".repeat(options.heading_level);
     let mut markdown = String::new();
 
-    for ty in list_of_types {
-        writeln!(markdown, "# {}", ty.name())?;
+    let anchors = section_anchors(&list_of_types);
+
+    if options.table_of_contents {
+        for (ty, anchor) in list_of_types.iter().zip(&anchors) {
+            writeln!(markdown, "- [{}](#{anchor})", ty.name())?;
+        }
+        writeln!(markdown)?;
+    }
+
+    for ty in &list_of_types {
+        writeln!(markdown, "{heading} {}", ty.name())?;
         writeln!(markdown)?;
         if let Some(doc) = ty.doc() {
             writeln!(markdown, "{}", doc)?;
         }
+        // Other types share their section between differently constrained uses, so their
+        // constraints are listed with the fields using them instead
+        let is_user_type = matches!(
+            ty.kind(),
+            crate::TypeKind::Wrapped(_) | crate::TypeKind::Struct(_) | crate::TypeKind::Enum(_, _)
+        );
+        if is_user_type && !ty.constraints().is_empty() {
+            writeln!(markdown)?;
+            writeln!(markdown, "_Constraints: {}_", ty.constraints())?;
+        }
         match ty.kind() {
-            crate::TypeKind::Bool
+            crate::TypeKind::Unit
+            | crate::TypeKind::Bool
+            | crate::TypeKind::Char
+            | crate::TypeKind::Bytes {
+                encoding: BytesEncoding::Raw,
+            }
             | crate::TypeKind::Integer { .. }
             | crate::TypeKind::Float { .. }
-            | crate::TypeKind::String => (),
+            | crate::TypeKind::String
+            | crate::TypeKind::Reference(_) => (),
+            crate::TypeKind::Bytes { encoding } => {
+                writeln!(markdown)?;
+                writeln!(markdown, "_Encoded as {encoding}_")?;
+            }
+            crate::TypeKind::Any => {
+                writeln!(markdown)?;
+                writeln!(markdown, "_Accepts any value_")?;
+            }
+            crate::TypeKind::FormattedString(format) => {
+                writeln!(markdown)?;
+                writeln!(markdown, "_Formatted as {format}_")?;
+            }
             crate::TypeKind::Wrapped(wrapped_ty) => {
                 writeln!(markdown)?;
-                writeln!(markdown, "_Represented by {}_", wrapped_ty.name())?;
+                writeln!(markdown, "_Represented by {}_", type_name(wrapped_ty))?;
+            }
+            crate::TypeKind::Optional(inner) => {
+                writeln!(markdown)?;
+                writeln!(markdown, "_Optional {}_", type_name(inner))?;
             }
             crate::TypeKind::Array(arr_ty) => {
                 writeln!(markdown)?;
-                writeln!(markdown, "_Array Elements of {}_", arr_ty.name())?;
+                writeln!(markdown, "_Array Elements of {}_", type_name(arr_ty))?;
+            }
+            crate::TypeKind::FixedArray {
+                element: element_ty,
+                length,
+            } => {
+                writeln!(markdown)?;
+                writeln!(
+                    markdown,
+                    "_Array of exactly {length} Elements of {}_",
+                    type_name(element_ty)
+                )?;
+            }
+            crate::TypeKind::Tuple(elements) => {
+                writeln!(markdown)?;
+                writeln!(
+                    markdown,
+                    "_Tuple of {}_",
+                    elements
+                        .iter()
+                        .map(type_name)
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )?;
             }
             crate::TypeKind::HashMap {
                 key: key_ty,
@@ -101,55 +537,57 @@ pub fn render_to_markdown(desc: &TypeDescription) -> Result<String, std::fmt::Er
                 writeln!(
                     markdown,
                     "_Key: {}, Values: {}_",
-                    key_ty.name(),
-                    value_ty.name()
+                    type_name(key_ty),
+                    type_name(value_ty)
                 )?;
             }
             crate::TypeKind::Struct(strct) => {
                 writeln!(markdown)?;
-                writeln!(markdown, "**Fields:**")?;
-                writeln!(markdown)?;
-
-                for field in strct {
-                    writeln!(
-                        markdown,
-                        "- `{}` ({}): {}",
-                        field.name(),
-                        field.kind().name(),
-                        field.doc().as_ref().unwrap_or(&"_No doc_")
-                    )?;
-                }
+                write_list_start(&mut markdown, "Fields", None, options)?;
+                write_fields(&mut markdown, strct, options)?;
+                write_list_end(&mut markdown, options)?;
             }
             crate::TypeKind::Enum(tag_kind, variants) => {
-                write!(markdown, "**Variants:** ")?;
-                if let TypeEnumKind::Tagged(tag) = tag_kind {
-                    writeln!(markdown, "(Tagged with field `{tag}`)")?;
-                } else {
-                    writeln!(markdown, "Untagged")?;
+                let tagging = match tag_kind {
+                    TypeEnumKind::External => String::from("(Externally tagged)"),
+                    TypeEnumKind::Tagged(tag) => format!("(Tagged with field `{tag}`)"),
+                    TypeEnumKind::Adjacent { tag, content } => {
+                        format!("(Tagged with field `{tag}`, content in field `{content}`)")
+                    }
+                    TypeEnumKind::Untagged => String::from("Untagged"),
                 };
-                writeln!(markdown)?;
+                write_list_start(&mut markdown, "Variants", Some(&tagging), options)?;
 
                 for variant in variants {
-                    match variant.repr() {
-                        crate::EnumVariantRepresentation::String(_) => {
-                            writeln!(
-                                markdown,
-                                "- `{}`: {}",
-                                variant.name(),
-                                variant.doc().unwrap_or("_No doc_")
-                            )?;
-                        }
-                        crate::EnumVariantRepresentation::Wrapped(wrapped_ty) => {
-                            writeln!(
-                                markdown,
-                                "- `{}` ({}): {}",
-                                variant.name(),
-                                wrapped_ty.name(),
-                                variant.doc().unwrap_or("_No doc_")
-                            )?;
+                    write!(markdown, "- `{}`", variant.name())?;
+                    let mut inline_fields = None;
+                    if let EnumVariantRepresentation::Wrapped(wrapped_ty) = variant.repr() {
+                        match (
+                            wrapped_ty.kind(),
+                            payload_section(wrapped_ty, &list_of_types),
+                        ) {
+                            (TypeKind::Struct(fields), _) => inline_fields = Some(fields),
+                            (_, Some((idx, target))) => {
+                                write!(markdown, " ([{}](#{}))", target.name(), anchors[idx])?
+                            }
+                            (TypeKind::Wrapped(inner), None) => {
+                                write!(markdown, " ({})", inner.name())?
+                            }
+                            (_, None) => write!(markdown, " ({})", wrapped_ty.name())?,
                         }
                     }
+                    match variant.doc().or(options.placeholder.as_deref()) {
+                        Some(doc) => writeln!(markdown, ": {doc}")?,
+                        None => writeln!(markdown)?,
+                    }
+                    for field in inline_fields
+                        .into_iter()
+                        .flat_map(|fields| ordered_fields(fields, options))
+                    {
+                        write_field_item(&mut markdown, "  ", field, options)?;
+                    }
                 }
+                write_list_end(&mut markdown, options)?;
             }
         }
         writeln!(markdown)?;
@@ -160,9 +598,14 @@ pub fn render_to_markdown(desc: &TypeDescription) -> Result<String, std::fmt::Er
 
 #[cfg(test)]
 mod tests {
-    use crate::AsTypeDescription;
+    use crate::{
+        AsTypeDescription, EnumVariant, EnumVariantRepresentation, StructField, TypeDescription,
+        TypeKind,
+    };
 
-    use super::render_to_markdown;
+    use super::{
+        render_to_markdown, render_to_markdown_with, FieldStyle, MarkdownOptions, TypeOrder,
+    };
 
     #[test]
     fn render_simple() {
@@ -171,6 +614,106 @@ mod tests {
 
         println!("{markdown}");
     }
+
+    #[test]
+    fn render_recursive() {
+        let ty_desc = TypeDescription::new(
+            String::from("Node"),
+            TypeKind::Struct(vec![StructField::new(
+                "parent",
+                None,
+                TypeDescription::reference("Node"),
+                false,
+            )]),
+            None,
+        );
+        let markdown = render_to_markdown(&ty_desc).unwrap();
+
+        assert_eq!(markdown.matches("# Node").count(), 1);
+        assert!(markdown.contains("- `parent` (Node)"));
+    }
+
+    #[test]
+    fn render_enum_payloads() {
+        let ty_desc = TypeDescription::enum_builder("Auth")
+            .variant(
+                "Gcp",
+                TypeDescription::builder("GcpConfig")
+                    .documented_field("token", "The token", String::as_type_description())
+                    .build(),
+            )
+            .push_variant(EnumVariant::new_owned(
+                "Basic",
+                Some("Username and password".into()),
+                EnumVariantRepresentation::Wrapped(Box::new(
+                    TypeDescription::builder("Basic")
+                        .field("user", String::as_type_description())
+                        .build(),
+                )),
+            ))
+            .build();
+        let options = MarkdownOptions::new().with_inline_primitives(true);
+
+        let markdown = render_to_markdown_with(&ty_desc, &options).unwrap();
+
+        assert!(markdown.contains("- `Gcp` ([GcpConfig](#gcpconfig)): _No doc_\n"));
+        assert!(markdown.contains(
+            "- `Basic`: Username and password\n  - `user` (String) _required_: _No doc_\n"
+        ));
+        assert!(markdown.contains("# GcpConfig\n"));
+        assert!(markdown.contains("- `token` (String) _required_: The token\n"));
+        assert!(!markdown.contains("# Basic\n"));
+        assert!(!markdown.contains("# Gcp\n"));
+    }
+
+    #[test]
+    fn render_with_options() {
+        let ty_desc = TypeDescription::builder("Config")
+            .optional_field("port", u16::as_type_description())
+            .documented_field(
+                "hosts",
+                "The hosts\nto | use",
+                Vec::<String>::as_type_description(),
+            )
+            .build();
+        let options = MarkdownOptions::new()
+            .with_heading_level(3)
+            .with_inline_primitives(true)
+            .with_order(TypeOrder::Name)
+            .with_table_of_contents(true)
+            .with_collapse(true)
+            .with_required_first(true)
+            .with_field_style(FieldStyle::Table)
+            .with_placeholder(None);
+
+        let markdown = render_to_markdown_with(&ty_desc, &options).unwrap();
+
+        assert_eq!(
+            markdown,
+            r#"- [Config](#config)
+- [Array of 'String's](#array-of-strings)
+
+### Config
+
+
+<details>
+<summary>Fields</summary>
+
+| Field | Type | Required | Description |
+| --- | --- | --- | --- |
+| `hosts` | Array of 'String's | Yes | The hosts<br>to \| use |
+| `port` | Integer | No |  |
+
+</details>
+
+### Array of 'String's
+
+
+_Array Elements of String_
+
+"#
+        );
+    }
 }
 
 // #[derive(TypeDescription)]
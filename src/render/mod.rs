@@ -13,3 +13,26 @@ pub use markdown::*;
 mod terminal;
 #[cfg(feature = "render_terminal")]
 pub use terminal::*;
+
+#[cfg(feature = "render_json_schema")]
+mod json_schema;
+#[cfg(feature = "render_json_schema")]
+pub use json_schema::*;
+
+#[cfg(any(feature = "render_toml", feature = "render_yaml"))]
+mod placeholder;
+
+#[cfg(feature = "render_toml")]
+mod toml;
+#[cfg(feature = "render_toml")]
+pub use self::toml::*;
+
+#[cfg(feature = "render_yaml")]
+mod yaml;
+#[cfg(feature = "render_yaml")]
+pub use yaml::*;
+
+#[cfg(feature = "render_html")]
+mod html;
+#[cfg(feature = "render_html")]
+pub use html::*;
@@ -0,0 +1,22 @@
+//
+//   This Source Code Form is subject to the terms of the Mozilla Public
+//   License, v. 2.0. If a copy of the MPL was not distributed with this
+//   file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+use crate::StringFormat;
+
+/// A plausible value for a string of the given format
+pub(super) fn string_example(format: StringFormat) -> &'static str {
+    match format {
+        StringFormat::SocketAddr => "127.0.0.1:8080",
+        StringFormat::Ip | StringFormat::Ipv4 => "127.0.0.1",
+        StringFormat::Ipv6 => "::1",
+        StringFormat::Url => "https://example.com",
+        StringFormat::Uuid => "00000000-0000-0000-0000-000000000000",
+        StringFormat::Path => "/path/to/file",
+        StringFormat::ByteSize => "1 KiB",
+        StringFormat::Duration => "1s",
+        StringFormat::DateTime => "1970-01-01T00:00:00Z",
+    }
+}
@@ -5,6 +5,7 @@
 //
 
 use nu_ansi_term::Color;
+use nu_ansi_term::Style;
 use pretty::Arena;
 use pretty::Doc;
 use pretty::DocAllocator;
@@ -12,11 +13,193 @@ use pretty::Pretty;
 use pretty::RefDoc;
 use termimad::MadSkin;
 
+use crate::BytesEncoding;
 use crate::EnumVariantRepresentation;
 use crate::TypeDescription;
 use crate::TypeEnumKind;
 use crate::TypeKind;
 
+/// The colors used by [`render_to_terminal_with`]
+///
+/// Every color is used in a few styles, e.g. member names are bold while the `[Members]` heading
+/// is not.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TerminalTheme {
+    name: Color,
+    members: Color,
+    enums: Color,
+    tags: Color,
+    arrays: Color,
+    details: Color,
+    required: Color,
+}
+
+impl Default for TerminalTheme {
+    fn default() -> Self {
+        Self::dark()
+    }
+}
+
+impl TerminalTheme {
+    /// Colors readable on a dark background, the default
+    pub fn dark() -> Self {
+        Self {
+            name: Color::LightBlue,
+            members: Color::Blue,
+            enums: Color::Green,
+            tags: Color::LightGreen,
+            arrays: Color::LightRed,
+            details: Color::White,
+            required: Color::LightRed,
+        }
+    }
+
+    /// Colors readable on a light background
+    pub fn light() -> Self {
+        Self {
+            name: Color::Blue,
+            members: Color::Blue,
+            enums: Color::Green,
+            tags: Color::Purple,
+            arrays: Color::Red,
+            details: Color::DarkGray,
+            required: Color::Red,
+        }
+    }
+
+    /// Set the color of type names
+    #[must_use]
+    pub fn with_name(mut self, color: Color) -> Self {
+        self.name = color;
+        self
+    }
+
+    /// Set the color of struct members
+    #[must_use]
+    pub fn with_members(mut self, color: Color) -> Self {
+        self.members = color;
+        self
+    }
+
+    /// Set the color of enum variants
+    #[must_use]
+    pub fn with_enums(mut self, color: Color) -> Self {
+        self.enums = color;
+        self
+    }
+
+    /// Set the color of the fields an enum is tagged with
+    #[must_use]
+    pub fn with_tags(mut self, color: Color) -> Self {
+        self.tags = color;
+        self
+    }
+
+    /// Set the color describing arrays, tuples, maps and variant contents
+    #[must_use]
+    pub fn with_arrays(mut self, color: Color) -> Self {
+        self.arrays = color;
+        self
+    }
+
+    /// Set the color of details like default values and constraints
+    #[must_use]
+    pub fn with_details(mut self, color: Color) -> Self {
+        self.details = color;
+        self
+    }
+
+    /// Set the color marking required members
+    #[must_use]
+    pub fn with_required(mut self, color: Color) -> Self {
+        self.required = color;
+        self
+    }
+}
+
+/// How documentation is rendered by [`render_to_terminal_with`]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum TerminalSkin {
+    /// For terminals with a dark background
+    #[default]
+    Dark,
+    /// For terminals with a light background
+    Light,
+}
+
+/// Options for [`render_to_terminal_with`]
+///
+/// The default options are the ones used by [`render_to_terminal`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TerminalOptions {
+    colors: bool,
+    skin: TerminalSkin,
+    theme: TerminalTheme,
+}
+
+impl Default for TerminalOptions {
+    fn default() -> Self {
+        Self {
+            colors: true,
+            skin: TerminalSkin::Dark,
+            theme: TerminalTheme::dark(),
+        }
+    }
+}
+
+impl TerminalOptions {
+    /// Create the default options
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Options for terminals with a light background
+    pub fn light() -> Self {
+        Self::default()
+            .with_skin(TerminalSkin::Light)
+            .with_theme(TerminalTheme::light())
+    }
+
+    /// Set whether the output contains escape codes for colors and text styles
+    ///
+    /// Disable this when the output does not go to a terminal, e.g. when writing to a file.
+    #[must_use]
+    pub fn with_colors(mut self, colors: bool) -> Self {
+        self.colors = colors;
+        self
+    }
+
+    /// Set how documentation is rendered
+    #[must_use]
+    pub fn with_skin(mut self, skin: TerminalSkin) -> Self {
+        self.skin = skin;
+        self
+    }
+
+    /// Set the colors used for everything but documentation
+    #[must_use]
+    pub fn with_theme(mut self, theme: TerminalTheme) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    fn paint(&self, style: Style, text: impl AsRef<str>) -> String {
+        if self.colors {
+            style.paint(text.as_ref()).to_string()
+        } else {
+            text.as_ref().to_string()
+        }
+    }
+
+    fn mad_skin(&self) -> MadSkin {
+        match (self.colors, self.skin) {
+            (false, _) => MadSkin::no_style(),
+            (true, TerminalSkin::Dark) => MadSkin::default_dark(),
+            (true, TerminalSkin::Light) => MadSkin::default_light(),
+        }
+    }
+}
+
 /// Get a [`RefDoc`](pretty::RefDoc) which can be converted into a String to print to a terminal
 /// with colors
 ///
@@ -34,31 +217,66 @@ use crate::TypeKind;
 /// println!("{}", output);
 /// ```
 pub fn render_to_terminal<'a>(desc: &'a TypeDescription, arena: &'a Arena<'a>) -> RefDoc<'a> {
+    render_to_terminal_with(desc, arena, &TerminalOptions::default())
+}
+
+/// Get a [`RefDoc`](pretty::RefDoc) to print to a terminal, with the given options
+///
+/// ## Example
+///
+/// ```rust
+/// use type_description::AsTypeDescription;
+/// use type_description::render::{render_to_terminal_with, TerminalOptions};
+///
+/// let input = Vec::<String>::as_type_description();
+/// let arena = pretty::Arena::new();
+///
+/// let options = TerminalOptions::new().with_colors(false);
+/// let rendered_doc = render_to_terminal_with(&input, &arena, &options);
+///
+/// let mut output = String::new();
+/// rendered_doc.render_fmt(80, &mut output).unwrap();
+/// assert_eq!(output, "Array of 'String's \nMany of: An UTF-8 string\n");
+/// ```
+pub fn render_to_terminal_with<'a>(
+    desc: &'a TypeDescription,
+    arena: &'a Arena<'a>,
+    options: &TerminalOptions,
+) -> RefDoc<'a> {
+    let theme = &options.theme;
     let mut doc = arena.nil();
 
-    if !matches!(desc.kind(), TypeKind::Wrapped(_)) && desc.doc().is_none() {
+    if !matches!(desc.kind(), TypeKind::Wrapped(_) | TypeKind::Optional(_)) && desc.doc().is_none()
+    {
         doc = doc
-            .append(Color::LightBlue.bold().paint(desc.name()).to_string())
+            .append(options.paint(theme.name.bold(), desc.name()))
             .append(arena.space())
             .append(match desc.kind() {
-                TypeKind::Bool
+                TypeKind::Unit
+                | TypeKind::Any
+                | TypeKind::Bool
+                | TypeKind::Char
+                | TypeKind::Bytes { .. }
                 | TypeKind::Integer { .. }
                 | TypeKind::Float { .. }
                 | TypeKind::String
+                | TypeKind::FormattedString(_)
                 | TypeKind::Wrapped(_)
+                | TypeKind::Optional(_)
                 | TypeKind::Array(_)
+                | TypeKind::FixedArray { .. }
+                | TypeKind::Tuple(_)
                 | TypeKind::HashMap { .. } => arena.nil(),
-                TypeKind::Struct(_) => {
-                    arena.text(Color::Blue.dimmed().paint("[Table]").to_string())
-                }
-                TypeKind::Enum(_, _) => {
-                    arena.text(Color::Green.dimmed().paint("[Enum]").to_string())
+                TypeKind::Struct(_) => arena.text(options.paint(theme.members.dimmed(), "[Table]")),
+                TypeKind::Enum(_, _) => arena.text(options.paint(theme.enums.dimmed(), "[Enum]")),
+                TypeKind::Reference(_) => {
+                    arena.text(options.paint(theme.details.dimmed(), "[Reference]"))
                 }
             })
             .append(arena.hardline());
     }
 
-    let skin = MadSkin::default_dark();
+    let skin = options.mad_skin();
     let render_markdown = |text: &str| {
         let rendered = skin.text(text, None).to_string();
         arena.intersperse(
@@ -76,53 +294,108 @@ pub fn render_to_terminal<'a>(desc: &'a TypeDescription, arena: &'a Arena<'a>) -
         doc = doc.append(render_markdown(conf_doc));
     }
 
+    if !desc.constraints().is_empty() {
+        doc = doc
+            .append(options.paint(
+                theme.details.dimmed(),
+                format!("[Constraints: {}]", desc.constraints()),
+            ))
+            .append(arena.hardline());
+    }
+
     match desc.kind() {
-        TypeKind::Bool | TypeKind::Integer { .. } | TypeKind::Float { .. } | TypeKind::String => (),
+        TypeKind::Unit
+        | TypeKind::Bool
+        | TypeKind::Char
+        | TypeKind::Bytes {
+            encoding: BytesEncoding::Raw,
+        }
+        | TypeKind::Integer { .. }
+        | TypeKind::Float { .. }
+        | TypeKind::String
+        | TypeKind::Reference(_) => (),
+        TypeKind::FormattedString(format) => {
+            doc = doc
+                .append(options.paint(theme.details.dimmed(), format!("[Format: {format}]")))
+                .append(arena.hardline());
+        }
+        TypeKind::Bytes { encoding } => {
+            doc = doc
+                .append(options.paint(theme.details.dimmed(), format!("[Encoding: {encoding}]")))
+                .append(arena.hardline());
+        }
         TypeKind::Struct(stc) => {
             doc = doc
                 .append(arena.hardline())
-                .append(Color::Blue.paint("[Members]").to_string())
+                .append(options.paint(theme.members.normal(), "[Members]"))
                 .append(arena.hardline())
                 .append(arena.intersperse(
                     stc.iter().map(|ev| {
                         let member_name = ev.name();
                         let member_doc = ev.doc();
-                        let member_conf = ev.kind();
+                        // Optional members are already marked as such
+                        let member_conf = match ev.kind().kind() {
+                            TypeKind::Optional(inner) if ev.optional() => inner,
+                            _ => ev.kind(),
+                        };
                         let mut doc = arena.nil();
 
                         if let Some(member_doc) = member_doc {
                             doc = doc.append(render_markdown(member_doc));
                         }
-                        doc.append(arena.text(Color::Blue.bold().paint(member_name).to_string()))
-                            .append(": ")
-                            .append(
-                                Pretty::pretty(render_to_terminal(member_conf, arena), arena)
-                                    .nest(4),
+                        doc = doc
+                            .append(arena.text(options.paint(theme.members.bold(), member_name)))
+                            .append(arena.space())
+                            .append(if ev.optional() {
+                                options.paint(theme.details.dimmed(), "(optional)")
+                            } else {
+                                options.paint(theme.required.dimmed(), "(required)")
+                            });
+                        #[cfg(feature = "serde_json")]
+                        if let Some(default) = ev.default_value() {
+                            doc = doc.append(arena.space()).append(
+                                options
+                                    .paint(theme.details.dimmed(), format!("(default: {default})")),
+                            );
+                        }
+                        doc.append(": ").append(
+                            Pretty::pretty(
+                                render_to_terminal_with(member_conf, arena, options),
+                                arena,
                             )
+                            .nest(4),
+                        )
                     }),
                     Doc::hardline(),
                 ))
         }
         TypeKind::Enum(enum_kind, variants) => {
+            let tag = |tag: &str| options.paint(theme.tags.italic().dimmed(), format!("'{tag}'"));
             doc = doc
                 .append(arena.hardline())
-                .append(Color::Green.paint("One of:").to_string())
+                .append(options.paint(theme.enums.normal(), "One of:"))
                 .append(arena.space())
                 .append(match enum_kind {
-                    TypeEnumKind::Tagged(tag) => arena.text(
-                        Color::White
-                            .dimmed()
-                            .paint(format!(
-                                "[Tagged with {}]",
-                                Color::LightGreen
-                                    .italic()
-                                    .dimmed()
-                                    .paint(format!("'{}'", tag))
-                            ))
-                            .to_string(),
-                    ),
+                    TypeEnumKind::External => {
+                        arena.text(options.paint(theme.details.dimmed(), "[Externally tagged]"))
+                    }
+                    TypeEnumKind::Adjacent {
+                        tag: tag_name,
+                        content,
+                    } => arena.text(options.paint(
+                        theme.details.dimmed(),
+                        format!(
+                            "[Tagged with {}, content in {}]",
+                            tag(tag_name),
+                            tag(content)
+                        ),
+                    )),
+                    TypeEnumKind::Tagged(tag_name) => arena.text(options.paint(
+                        theme.details.dimmed(),
+                        format!("[Tagged with {}]", tag(tag_name)),
+                    )),
                     TypeEnumKind::Untagged => {
-                        arena.text(Color::White.dimmed().paint("[Untagged]").to_string())
+                        arena.text(options.paint(theme.details.dimmed(), "[Untagged]"))
                     }
                 })
                 .append(arena.hardline())
@@ -136,13 +409,11 @@ pub fn render_to_terminal<'a>(desc: &'a TypeDescription, arena: &'a Arena<'a>) -
                                 .nil()
                                 .append(match member_conf {
                                     EnumVariantRepresentation::String(rep) => arena.text(
-                                        Color::Green
-                                            .bold()
-                                            .paint(&format!("{:?}", rep.to_lowercase()))
-                                            .to_string(),
+                                        options.paint(theme.enums.bold(), format!("{rep:?}")),
                                     ),
-                                    EnumVariantRepresentation::Wrapped(_) => arena
-                                        .text(Color::Green.bold().paint(member_name).to_string()),
+                                    EnumVariantRepresentation::Wrapped(_) => {
+                                        arena.text(options.paint(theme.enums.bold(), member_name))
+                                    }
                                 })
                                 .append(": ");
 
@@ -158,8 +429,12 @@ pub fn render_to_terminal<'a>(desc: &'a TypeDescription, arena: &'a Arena<'a>) -
                                         }
 
                                         EnumVariantRepresentation::Wrapped(member_conf) => arena
-                                            .text(Color::LightRed.paint("Is a: ").to_string())
-                                            .append(render_to_terminal(member_conf, arena))
+                                            .text(options.paint(theme.arrays.normal(), "Is a: "))
+                                            .append(render_to_terminal_with(
+                                                member_conf,
+                                                arena,
+                                                options,
+                                            ))
                                             .into_doc(),
                                     },
                                     arena,
@@ -174,22 +449,56 @@ pub fn render_to_terminal<'a>(desc: &'a TypeDescription, arena: &'a Arena<'a>) -
         }
         TypeKind::Array(conf) => {
             doc = doc
-                .append(Color::LightRed.paint("Many of:").to_string())
+                .append(options.paint(theme.arrays.normal(), "Many of:"))
                 .append(arena.space())
-                .append(render_to_terminal(conf, arena));
+                .append(render_to_terminal_with(conf, arena, options));
+        }
+        TypeKind::FixedArray { element, length } => {
+            doc = doc
+                .append(options.paint(theme.arrays.normal(), format!("Exactly {length} of:")))
+                .append(arena.space())
+                .append(render_to_terminal_with(element, arena, options));
+        }
+        TypeKind::Tuple(elements) => {
+            doc = doc
+                .append(options.paint(theme.arrays.normal(), "Tuple of:"))
+                .append(
+                    arena
+                        .hardline()
+                        .append(arena.intersperse(
+                            elements.iter().map(|element| {
+                                arena
+                                    .text("-")
+                                    .append(arena.space())
+                                    .append(render_to_terminal_with(element, arena, options))
+                                    .nest(2)
+                            }),
+                            Doc::hardline(),
+                        ))
+                        .nest(2),
+                );
         }
         TypeKind::HashMap { key, value } => {
             doc = doc
-                .append(Color::LightRed.paint("Hashmap of").to_string())
+                .append(options.paint(theme.arrays.normal(), "Hashmap of"))
                 .append(arena.space())
                 .append(key.name())
                 .append(arena.space())
-                .append(Color::LightRed.paint("to").to_string())
+                .append(options.paint(theme.arrays.normal(), "to"))
                 .append(arena.space())
                 .append(value.name());
         }
         TypeKind::Wrapped(conf) => {
-            doc = doc.append(render_to_terminal(conf, arena));
+            doc = doc.append(render_to_terminal_with(conf, arena, options));
+        }
+        TypeKind::Optional(conf) => {
+            doc = doc
+                .append(options.paint(theme.details.dimmed(), "[Optional]"))
+                .append(arena.space())
+                .append(render_to_terminal_with(conf, arena, options));
+        }
+        TypeKind::Any => {
+            doc = doc.append(options.paint(theme.details.dimmed(), "[Any value]"));
         }
     };
 
@@ -0,0 +1,537 @@
+//
+//   This Source Code Form is subject to the terms of the Mozilla Public
+//   License, v. 2.0. If a copy of the MPL was not distributed with this
+//   file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+use std::{collections::BTreeMap, fmt::Write};
+
+use serde_json::Value;
+
+use super::placeholder::string_example;
+use crate::{
+    registry::named_types, BytesEncoding, EnumVariant, EnumVariantRepresentation, StructField,
+    TypeDescription, TypeEnumKind, TypeKind,
+};
+
+fn quote(string: &str) -> String {
+    // JSON string escapes are a subset of the ones TOML basic strings support
+    Value::from(string).to_string()
+}
+
+fn key(name: &str) -> String {
+    let is_bare = !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+
+    if is_bare {
+        name.to_string()
+    } else {
+        quote(name)
+    }
+}
+
+fn inline_table(entries: impl IntoIterator<Item = (String, String)>) -> String {
+    let entries = entries
+        .into_iter()
+        .map(|(name, value)| format!("{} = {value}", key(&name)))
+        .collect::<Vec<_>>();
+
+    if entries.is_empty() {
+        String::from("{}")
+    } else {
+        format!("{{ {} }}", entries.join(", "))
+    }
+}
+
+/// Convert a serialized default value to TOML, `null` has no representation
+fn toml_value(value: &Value) -> Option<String> {
+    Some(match value {
+        Value::Null => return None,
+        Value::Bool(_) | Value::Number(_) | Value::String(_) => value.to_string(),
+        Value::Array(values) => format!(
+            "[{}]",
+            values
+                .iter()
+                .map(toml_value)
+                .collect::<Option<Vec<_>>>()?
+                .join(", ")
+        ),
+        Value::Object(values) => inline_table(
+            values
+                .iter()
+                .map(|(name, value)| Some((name.clone(), toml_value(value)?)))
+                .collect::<Option<Vec<_>>>()?,
+        ),
+    })
+}
+
+fn write_comment(toml: &mut String, text: &str) -> std::fmt::Result {
+    for line in text.lines() {
+        if line.trim().is_empty() {
+            writeln!(toml, "#")?;
+        } else {
+            writeln!(toml, "# {}", line.trim_end())?;
+        }
+    }
+    Ok(())
+}
+
+/// How a value is placed in a TOML document
+enum Shape<'a> {
+    /// A `[table]` of its own
+    Table(&'a TypeDescription, &'a [StructField]),
+    /// An `[[array]]` of tables
+    TableArray(&'a TypeDescription, &'a [StructField]),
+    /// A value written next to its key
+    Inline,
+}
+
+struct TomlRenderer<'a> {
+    definitions: BTreeMap<&'a str, &'a TypeDescription>,
+    /// The structs currently being rendered, used to cut off recursive types
+    in_progress: Vec<&'a str>,
+}
+
+impl<'a> TomlRenderer<'a> {
+    /// Skip over wrappers and references to the type that determines the representation
+    fn inner(&self, desc: &'a TypeDescription) -> &'a TypeDescription {
+        match desc.kind() {
+            TypeKind::Wrapped(inner) | TypeKind::Optional(inner) => self.inner(inner),
+            TypeKind::Reference(name) => match self.definitions.get(name.as_str()) {
+                Some(definition) => self.inner(definition),
+                None => desc,
+            },
+            _ => desc,
+        }
+    }
+
+    fn is_in_progress(&self, desc: &TypeDescription) -> bool {
+        self.in_progress.contains(&desc.name())
+    }
+
+    fn shape(&self, desc: &'a TypeDescription) -> Shape<'a> {
+        let inner = self.inner(desc);
+        match inner.kind() {
+            TypeKind::Struct(fields) if !self.is_in_progress(inner) => Shape::Table(inner, fields),
+            TypeKind::Array(element) => {
+                let element = self.inner(element);
+                match element.kind() {
+                    TypeKind::Struct(fields) if !self.is_in_progress(element) => {
+                        Shape::TableArray(element, fields)
+                    }
+                    _ => Shape::Inline,
+                }
+            }
+            _ => Shape::Inline,
+        }
+    }
+
+    /// A placeholder value for the given type, written in a single line
+    fn inline(&mut self, desc: &'a TypeDescription) -> String {
+        let inner = self.inner(desc);
+        let min = desc.constraints().min().or(inner.constraints().min());
+
+        match inner.kind() {
+            // TOML has no null, an empty table is the closest to no value
+            TypeKind::Unit | TypeKind::Any => String::from("{}"),
+            TypeKind::Bool => String::from("false"),
+            TypeKind::Char => quote("a"),
+            TypeKind::Bytes {
+                encoding: BytesEncoding::Raw,
+            } => String::from("[]"),
+            TypeKind::Bytes { .. } => quote(""),
+            TypeKind::Integer { .. } => min.map_or_else(|| String::from("0"), ToString::to_string),
+            TypeKind::Float { .. } => min.map_or_else(
+                || String::from("0.0"),
+                |min| format!("{:?}", min.as_f64().unwrap_or_default()),
+            ),
+            TypeKind::String => quote(""),
+            TypeKind::FormattedString(format) => quote(string_example(*format)),
+            TypeKind::Array(element) => {
+                let element = self.inner(element);
+                if matches!(element.kind(), TypeKind::Struct(_)) && self.is_in_progress(element) {
+                    String::from("[]")
+                } else {
+                    format!("[{}]", self.inline(element))
+                }
+            }
+            TypeKind::FixedArray { element, length } => {
+                let element = self.inline(element);
+                format!("[{}]", vec![element; *length].join(", "))
+            }
+            TypeKind::Tuple(elements) => format!(
+                "[{}]",
+                elements
+                    .iter()
+                    .map(|element| self.inline(element))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            TypeKind::HashMap { value, .. } => {
+                inline_table([(String::from("key"), self.inline(value))])
+            }
+            TypeKind::Struct(_) if self.is_in_progress(inner) => String::from("{}"),
+            TypeKind::Struct(fields) => {
+                self.in_progress.push(inner.name());
+                let entries = fields
+                    .iter()
+                    .map(|field| (field.name().to_string(), self.field_value(field)))
+                    .collect::<Vec<_>>();
+                self.in_progress.pop();
+                inline_table(entries)
+            }
+            TypeKind::Enum(enum_kind, variants) => variants
+                .iter()
+                .find_map(|variant| self.variant_example(enum_kind, variant))
+                .unwrap_or_else(|| String::from("{}")),
+            TypeKind::Wrapped(_) | TypeKind::Optional(_) | TypeKind::Reference(_) => {
+                String::from("{}")
+            }
+        }
+    }
+
+    fn field_value(&mut self, field: &'a StructField) -> String {
+        field
+            .default_value()
+            .and_then(|default| toml_value(default.value()))
+            .unwrap_or_else(|| self.inline(field.kind()))
+    }
+
+    /// An example of how the given variant is written
+    ///
+    /// Untagged unit variants are only accepted as `null`, which TOML cannot express.
+    fn variant_example(
+        &mut self,
+        enum_kind: &TypeEnumKind,
+        variant: &'a EnumVariant,
+    ) -> Option<String> {
+        let payload = match variant.repr() {
+            EnumVariantRepresentation::String(repr) => {
+                return match enum_kind {
+                    TypeEnumKind::External => Some(quote(repr)),
+                    TypeEnumKind::Tagged(tag) | TypeEnumKind::Adjacent { tag, .. } => {
+                        Some(inline_table([(tag.to_string(), quote(repr))]))
+                    }
+                    TypeEnumKind::Untagged => None,
+                };
+            }
+            EnumVariantRepresentation::Wrapped(payload) => payload,
+        };
+
+        let example = match enum_kind {
+            TypeEnumKind::External => {
+                inline_table([(variant.name().to_string(), self.inline(payload))])
+            }
+            TypeEnumKind::Tagged(tag) => {
+                let mut entries = vec![(tag.to_string(), quote(variant.name()))];
+                if let TypeKind::Struct(fields) = self.inner(payload).kind() {
+                    entries.extend(
+                        fields
+                            .iter()
+                            .map(|field| (field.name().to_string(), self.field_value(field))),
+                    );
+                }
+                inline_table(entries)
+            }
+            TypeEnumKind::Adjacent { tag, content } => inline_table([
+                (tag.to_string(), quote(variant.name())),
+                (content.to_string(), self.inline(payload)),
+            ]),
+            TypeEnumKind::Untagged => self.inline(payload),
+        };
+        Some(example)
+    }
+
+    /// Describe the possible variants if the given type is an enum
+    fn variants_comment(&mut self, desc: &'a TypeDescription) -> Option<String> {
+        let TypeKind::Enum(enum_kind, variants) = self.inner(desc).kind() else {
+            return None;
+        };
+
+        let mut comment = String::from("One of:");
+        let mut has_examples = false;
+        for variant in variants {
+            let Some(example) = self.variant_example(enum_kind, variant) else {
+                continue;
+            };
+            has_examples = true;
+            comment.push_str("\n  - ");
+            comment.push_str(&example);
+            if let Some(doc) = variant.doc() {
+                comment.push_str(": ");
+                comment.push_str(doc.lines().next().unwrap_or_default().trim());
+            }
+        }
+        has_examples.then_some(comment)
+    }
+
+    fn write_docs(&mut self, toml: &mut String, field: &'a StructField) -> std::fmt::Result {
+        if let Some(doc) = field.doc().or(field.kind().doc()) {
+            write_comment(toml, doc)?;
+        }
+        if let Some(variants) = self.variants_comment(field.kind()) {
+            write_comment(toml, &variants)?;
+        }
+        Ok(())
+    }
+
+    fn write_table(
+        &mut self,
+        toml: &mut String,
+        path: &str,
+        fields: &'a [StructField],
+        commented: bool,
+    ) -> std::fmt::Result {
+        let (inline, tables): (Vec<_>, Vec<_>) = fields
+            .iter()
+            .map(|field| (field, self.shape(field.kind())))
+            .partition(|(_, shape)| matches!(shape, Shape::Inline));
+
+        // Plain keys have to come before any sub-tables, as those would otherwise capture them
+        for (field, _) in inline {
+            self.write_docs(toml, field)?;
+            let prefix = if commented || field.optional() {
+                "# "
+            } else {
+                ""
+            };
+            let value = self.field_value(field);
+            writeln!(toml, "{prefix}{} = {value}", key(field.name()))?;
+        }
+
+        for (field, shape) in tables {
+            let sub_path = if path.is_empty() {
+                key(field.name())
+            } else {
+                format!("{path}.{}", key(field.name()))
+            };
+            let commented = commented || field.optional();
+            let prefix = if commented { "# " } else { "" };
+
+            writeln!(toml)?;
+            self.write_docs(toml, field)?;
+            let (table, fields) = match shape {
+                Shape::Table(table, fields) => {
+                    writeln!(toml, "{prefix}[{sub_path}]")?;
+                    (table, fields)
+                }
+                Shape::TableArray(table, fields) => {
+                    writeln!(toml, "{prefix}[[{sub_path}]]")?;
+                    (table, fields)
+                }
+                Shape::Inline => unreachable!("Inline fields were written above"),
+            };
+
+            self.in_progress.push(table.name());
+            self.write_table(toml, &sub_path, fields, commented)?;
+            self.in_progress.pop();
+        }
+
+        Ok(())
+    }
+}
+
+/// Render a [`struct@TypeDescription`] to a commented TOML document
+///
+/// The document is meant as a starting point for a configuration file: every field is written
+/// with its default value or a placeholder, its documentation is placed above it in comments and
+/// optional fields are commented out. Enums list all their variants in a comment.
+///
+/// Only structs can be represented as a whole TOML document, any other type is rendered as a
+/// single commented out value.
+///
+/// # Example
+///
+/// ```rust
+///     use type_description::{AsTypeDescription, TypeDescription};
+///     use type_description::render::render_to_toml;
+///
+///     /// A server
+///     #[derive(TypeDescription)]
+///     struct Config {
+///         /// The address to listen on
+///         addr: std::net::SocketAddr,
+///     }
+///
+///     let toml = render_to_toml(&Config::as_type_description()).unwrap();
+///
+///     assert!(toml.contains("# The address to listen on\naddr = \"127.0.0.1:8080\""));
+/// ```
+pub fn render_to_toml(desc: &TypeDescription) -> Result<String, std::fmt::Error> {
+    let mut renderer = TomlRenderer {
+        definitions: named_types(desc),
+        in_progress: vec![],
+    };
+
+    let mut toml = String::new();
+    if let Some(doc) = desc.doc() {
+        write_comment(&mut toml, doc)?;
+        writeln!(toml)?;
+    }
+
+    let root = renderer.inner(desc);
+    match root.kind() {
+        TypeKind::Struct(fields) => {
+            renderer.in_progress.push(root.name());
+            renderer.write_table(&mut toml, "", fields, false)?;
+        }
+        _ => {
+            let value = renderer.inline(desc);
+            writeln!(toml, "# {value}")?;
+        }
+    }
+
+    Ok(toml)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        AsTypeDescription, EnumVariant, EnumVariantRepresentation, StructField, TypeDescription,
+        TypeEnumKind, TypeKind,
+    };
+
+    use super::render_to_toml;
+
+    fn server() -> TypeDescription {
+        TypeDescription::new(
+            String::from("Server"),
+            TypeKind::Struct(vec![
+                StructField::new(
+                    "name",
+                    Some("The server's name"),
+                    String::as_type_description(),
+                    false,
+                ),
+                StructField::new("port", None, u16::as_type_description(), true),
+            ]),
+            None,
+        )
+    }
+
+    #[test]
+    fn render_tables() {
+        let desc = TypeDescription::new(
+            String::from("Config"),
+            TypeKind::Struct(vec![
+                StructField::new(
+                    "servers",
+                    Some("All servers"),
+                    TypeDescription::new(
+                        String::from("Array of 'Server's"),
+                        TypeKind::Array(Box::new(server())),
+                        None,
+                    ),
+                    false,
+                ),
+                StructField::new("main", None, server(), true),
+                StructField::new("debug", None, bool::as_type_description(), false),
+            ]),
+            Some("The configuration"),
+        );
+
+        let toml = render_to_toml(&desc).unwrap();
+
+        assert_eq!(
+            toml,
+            r#"# The configuration
+
+# A boolean
+debug = false
+
+# All servers
+[[servers]]
+# The server's name
+name = ""
+# An unsigned integer with 16 bits
+# port = 0
+
+# [main]
+# The server's name
+# name = ""
+# An unsigned integer with 16 bits
+# port = 0
+"#
+        );
+    }
+
+    #[test]
+    fn render_enum_variants() {
+        let desc = TypeDescription::new(
+            String::from("Config"),
+            TypeKind::Struct(vec![StructField::new(
+                "door",
+                None,
+                TypeDescription::new(
+                    String::from("Door"),
+                    TypeKind::Enum(
+                        TypeEnumKind::Tagged("kind".into()),
+                        vec![
+                            EnumVariant::new(
+                                "Wooden",
+                                Some("A wooden door"),
+                                EnumVariantRepresentation::String("wooden".into()),
+                            ),
+                            EnumVariant::new(
+                                "Steel",
+                                None,
+                                EnumVariantRepresentation::Wrapped(Box::new(server())),
+                            ),
+                        ],
+                    ),
+                    None,
+                ),
+                false,
+            )]),
+            None,
+        );
+
+        let toml = render_to_toml(&desc).unwrap();
+
+        assert!(toml.contains("#   - { kind = \"wooden\" }: A wooden door\n"));
+        assert!(toml.contains("#   - { kind = \"Steel\", name = \"\", port = 0 }\n"));
+        assert!(toml.contains("\ndoor = { kind = \"wooden\" }\n"));
+    }
+
+    #[test]
+    fn render_untagged_enum_variants() {
+        let desc = TypeDescription::new(
+            String::from("Config"),
+            TypeKind::Struct(vec![StructField::new(
+                "limit",
+                None,
+                TypeDescription::new(
+                    String::from("Limit"),
+                    TypeKind::Enum(
+                        TypeEnumKind::Untagged,
+                        vec![
+                            EnumVariant::new(
+                                "Off",
+                                Some("No limit"),
+                                EnumVariantRepresentation::String("Off".into()),
+                            ),
+                            EnumVariant::new(
+                                "Fixed",
+                                Some("A fixed limit"),
+                                EnumVariantRepresentation::Wrapped(Box::new(
+                                    u8::as_type_description(),
+                                )),
+                            ),
+                        ],
+                    ),
+                    None,
+                ),
+                false,
+            )]),
+            None,
+        );
+
+        let toml = render_to_toml(&desc).unwrap();
+
+        assert!(!toml.contains("Off"));
+        assert!(toml.contains("# One of:\n#   - 0: A fixed limit\n"));
+        assert!(toml.contains("\nlimit = 0\n"));
+    }
+}
@@ -0,0 +1,514 @@
+//
+//   This Source Code Form is subject to the terms of the Mozilla Public
+//   License, v. 2.0. If a copy of the MPL was not distributed with this
+//   file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+use std::{collections::BTreeMap, fmt::Write};
+
+use serde_json::Value;
+
+use super::placeholder::string_example;
+use crate::{
+    registry::named_types, BytesEncoding, EnumVariant, EnumVariantRepresentation, StructField,
+    TypeDescription, TypeEnumKind, TypeKind,
+};
+
+/// Write a string as a plain scalar if that cannot be mistaken for anything else
+fn scalar(string: &str) -> String {
+    let is_plain = string
+        .chars()
+        .next()
+        .map_or(false, |c| c.is_ascii_alphabetic() || c == '_')
+        && string
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+        && !matches!(
+            string.to_ascii_lowercase().as_str(),
+            "true" | "false" | "null" | "yes" | "no" | "on" | "off" | "y" | "n"
+        );
+
+    if is_plain {
+        string.to_string()
+    } else {
+        // Double quoted YAML scalars understand all JSON escapes
+        Value::from(string).to_string()
+    }
+}
+
+/// An example YAML value
+enum Node {
+    Scalar(String),
+    Mapping(Vec<Entry>),
+    Sequence(Vec<Node>),
+}
+
+/// A key in a mapping, with all the values it could take
+///
+/// The first value is written out, all others are commented out below it.
+struct Entry {
+    key: String,
+    doc: Option<String>,
+    optional: bool,
+    values: Vec<(Option<String>, Node)>,
+}
+
+impl Entry {
+    fn new(key: &str, doc: Option<&str>, optional: bool, value: Node) -> Self {
+        Entry {
+            key: key.to_string(),
+            doc: doc.map(String::from),
+            optional,
+            values: vec![(None, value)],
+        }
+    }
+}
+
+impl From<&Value> for Node {
+    fn from(value: &Value) -> Self {
+        match value {
+            Value::Null | Value::Bool(_) | Value::Number(_) => Node::Scalar(value.to_string()),
+            Value::String(string) => Node::Scalar(scalar(string)),
+            Value::Array(values) => Node::Sequence(values.iter().map(Node::from).collect()),
+            Value::Object(values) => Node::Mapping(
+                values
+                    .iter()
+                    .map(|(key, value)| Entry::new(key, None, false, Node::from(value)))
+                    .collect(),
+            ),
+        }
+    }
+}
+
+struct Line {
+    indent: usize,
+    text: String,
+    comment: bool,
+    /// The column of the `#` when this line is part of a commented out block
+    disabled_at: Option<usize>,
+}
+
+impl Line {
+    fn text(indent: usize, text: String) -> Self {
+        Line {
+            indent,
+            text,
+            comment: false,
+            disabled_at: None,
+        }
+    }
+
+    fn comments(indent: usize, text: &str) -> impl Iterator<Item = Line> + '_ {
+        text.lines().map(move |line| Line {
+            indent,
+            text: line.trim_end().to_string(),
+            comment: true,
+            disabled_at: None,
+        })
+    }
+}
+
+/// Comment out a block starting at `indent`, outer blocks are disabled last and take precedence
+fn disable(lines: &mut [Line], indent: usize) {
+    for line in lines {
+        line.disabled_at = Some(indent);
+    }
+}
+
+fn entries_lines(entries: &[Entry], indent: usize) -> Vec<Line> {
+    let mut lines = vec![];
+    for entry in entries {
+        if let Some(doc) = &entry.doc {
+            lines.extend(Line::comments(indent, doc));
+        }
+
+        for (idx, (label, node)) in entry.values.iter().enumerate() {
+            if let Some(label) = label {
+                lines.extend(Line::comments(indent, label));
+            }
+
+            let key = scalar(&entry.key);
+            let mut value_lines = match node {
+                Node::Scalar(value) => vec![Line::text(indent, format!("{key}: {value}"))],
+                Node::Mapping(entries) if entries.is_empty() => {
+                    vec![Line::text(indent, format!("{key}: {{}}"))]
+                }
+                Node::Sequence(items) if items.is_empty() => {
+                    vec![Line::text(indent, format!("{key}: []"))]
+                }
+                Node::Mapping(entries) => {
+                    let mut lines = vec![Line::text(indent, format!("{key}:"))];
+                    lines.extend(entries_lines(entries, indent + 2));
+                    lines
+                }
+                Node::Sequence(items) => {
+                    let mut lines = vec![Line::text(indent, format!("{key}:"))];
+                    lines.extend(items.iter().flat_map(|item| item_lines(item, indent + 2)));
+                    lines
+                }
+            };
+
+            if idx > 0 || entry.optional {
+                disable(&mut value_lines, indent);
+            }
+            lines.extend(value_lines);
+        }
+    }
+    lines
+}
+
+fn item_lines(node: &Node, indent: usize) -> Vec<Line> {
+    let mut lines = match node {
+        Node::Scalar(value) => return vec![Line::text(indent, format!("- {value}"))],
+        Node::Mapping(entries) => entries_lines(entries, indent + 2),
+        Node::Sequence(items) => items
+            .iter()
+            .flat_map(|item| item_lines(item, indent + 2))
+            .collect(),
+    };
+
+    // The item starts at its first line that is not commented out
+    match lines
+        .iter_mut()
+        .find(|line| !line.comment && line.disabled_at.is_none())
+    {
+        Some(first) => {
+            first.indent = indent;
+            first.text = format!("- {}", first.text);
+        }
+        None => {
+            let empty = if matches!(node, Node::Mapping(_)) {
+                "{}"
+            } else {
+                "[]"
+            };
+            lines.insert(0, Line::text(indent, format!("- {empty}")));
+        }
+    }
+    lines
+}
+
+struct YamlRenderer<'a> {
+    definitions: BTreeMap<&'a str, &'a TypeDescription>,
+    /// The structs currently being rendered, used to cut off recursive types
+    in_progress: Vec<&'a str>,
+}
+
+impl<'a> YamlRenderer<'a> {
+    /// Skip over wrappers and references to the type that determines the representation
+    fn inner(&self, desc: &'a TypeDescription) -> &'a TypeDescription {
+        match desc.kind() {
+            TypeKind::Wrapped(inner) | TypeKind::Optional(inner) => self.inner(inner),
+            TypeKind::Reference(name) => match self.definitions.get(name.as_str()) {
+                Some(definition) => self.inner(definition),
+                None => desc,
+            },
+            _ => desc,
+        }
+    }
+
+    fn node(&mut self, desc: &'a TypeDescription) -> Node {
+        let inner = self.inner(desc);
+        let min = desc.constraints().min().or(inner.constraints().min());
+
+        match inner.kind() {
+            TypeKind::Unit => Node::Scalar(String::from("null")),
+            TypeKind::Any => Node::Mapping(vec![]),
+            TypeKind::Bool => Node::Scalar(String::from("false")),
+            TypeKind::Char => Node::Scalar(scalar("a")),
+            TypeKind::Bytes {
+                encoding: BytesEncoding::Raw,
+            } => Node::Sequence(vec![]),
+            TypeKind::Bytes { .. } => Node::Scalar(scalar("")),
+            TypeKind::Integer { .. } => {
+                Node::Scalar(min.map_or_else(|| String::from("0"), ToString::to_string))
+            }
+            TypeKind::Float { .. } => Node::Scalar(min.map_or_else(
+                || String::from("0.0"),
+                |min| format!("{:?}", min.as_f64().unwrap_or_default()),
+            )),
+            TypeKind::String => Node::Scalar(scalar("")),
+            TypeKind::FormattedString(format) => Node::Scalar(scalar(string_example(*format))),
+            TypeKind::Array(element) => {
+                let element = self.inner(element);
+                if self.in_progress.contains(&element.name()) {
+                    Node::Sequence(vec![])
+                } else {
+                    Node::Sequence(vec![self.node(element)])
+                }
+            }
+            TypeKind::FixedArray { element, length } => {
+                Node::Sequence((0..*length).map(|_| self.node(element)).collect())
+            }
+            TypeKind::Tuple(elements) => {
+                Node::Sequence(elements.iter().map(|element| self.node(element)).collect())
+            }
+            TypeKind::HashMap { value, .. } => {
+                Node::Mapping(vec![Entry::new("key", None, false, self.node(value))])
+            }
+            TypeKind::Struct(_) if self.in_progress.contains(&inner.name()) => {
+                Node::Mapping(vec![])
+            }
+            TypeKind::Struct(fields) => {
+                self.in_progress.push(inner.name());
+                let entries = self.field_entries(fields);
+                self.in_progress.pop();
+                Node::Mapping(entries)
+            }
+            TypeKind::Enum(enum_kind, variants) => match variants.first() {
+                Some(variant) => self.variant_node(enum_kind, variant),
+                None => Node::Mapping(vec![]),
+            },
+            TypeKind::Wrapped(_) | TypeKind::Optional(_) | TypeKind::Reference(_) => {
+                Node::Mapping(vec![])
+            }
+        }
+    }
+
+    /// Every way to write a value of the given type, the first one being the preferred one
+    fn alternatives(&mut self, desc: &'a TypeDescription) -> Vec<(Option<String>, Node)> {
+        let TypeKind::Enum(enum_kind, variants) = self.inner(desc).kind() else {
+            return vec![(None, self.node(desc))];
+        };
+
+        variants
+            .iter()
+            .map(|variant| {
+                let label = match variant.doc() {
+                    Some(doc) => format!("Variant `{}`: {}", variant.name(), doc.trim()),
+                    None => format!("Variant `{}`", variant.name()),
+                };
+                (Some(label), self.variant_node(enum_kind, variant))
+            })
+            .collect()
+    }
+
+    fn field_entries(&mut self, fields: &'a [StructField]) -> Vec<Entry> {
+        fields
+            .iter()
+            .map(|field| {
+                let values = match field.default_value() {
+                    Some(default) => vec![(None, Node::from(default.value()))],
+                    None => self.alternatives(field.kind()),
+                };
+
+                Entry {
+                    key: field.name().to_string(),
+                    doc: field.doc().or(field.kind().doc()).map(String::from),
+                    optional: field.optional(),
+                    values,
+                }
+            })
+            .collect()
+    }
+
+    fn variant_node(&mut self, enum_kind: &TypeEnumKind, variant: &'a EnumVariant) -> Node {
+        let payload = match variant.repr() {
+            EnumVariantRepresentation::String(repr) => {
+                return match enum_kind {
+                    TypeEnumKind::External => Node::Scalar(scalar(repr)),
+                    // Like serde, untagged unit variants are written as `null` instead of their name
+                    TypeEnumKind::Untagged => Node::Scalar(String::from("null")),
+                    TypeEnumKind::Tagged(tag) | TypeEnumKind::Adjacent { tag, .. } => {
+                        Node::Mapping(vec![Entry::new(
+                            tag,
+                            None,
+                            false,
+                            Node::Scalar(scalar(repr)),
+                        )])
+                    }
+                };
+            }
+            EnumVariantRepresentation::Wrapped(payload) => payload,
+        };
+
+        match enum_kind {
+            TypeEnumKind::External => Node::Mapping(vec![Entry::new(
+                variant.name(),
+                None,
+                false,
+                self.node(payload),
+            )]),
+            TypeEnumKind::Tagged(tag) => {
+                let mut entries = vec![Entry::new(
+                    tag,
+                    None,
+                    false,
+                    Node::Scalar(scalar(variant.name())),
+                )];
+                if let TypeKind::Struct(fields) = self.inner(payload).kind() {
+                    entries.extend(self.field_entries(fields));
+                }
+                Node::Mapping(entries)
+            }
+            TypeEnumKind::Adjacent { tag, content } => Node::Mapping(vec![
+                Entry::new(tag, None, false, Node::Scalar(scalar(variant.name()))),
+                Entry::new(content, None, false, self.node(payload)),
+            ]),
+            TypeEnumKind::Untagged => self.node(payload),
+        }
+    }
+}
+
+/// Render a [`struct@TypeDescription`] to a commented YAML document
+///
+/// Like [`render_to_toml`](super::render_to_toml) the result is meant to be edited into a
+/// configuration file. Fields are written with their default values or placeholders and their
+/// documentation as comments, optional fields are commented out.
+///
+/// For enums, the first variant is written out and every other variant follows as a commented
+/// out alternative. This shows the tag field of tagged enums and every shape an untagged enum can
+/// take.
+///
+/// # Example
+///
+/// ```rust
+///     use type_description::{AsTypeDescription, TypeDescription};
+///     use type_description::render::render_to_yaml;
+///
+///     #[derive(TypeDescription)]
+///     struct Config {
+///         /// The hosts to connect to
+///         hosts: Vec<String>,
+///     }
+///
+///     let yaml = render_to_yaml(&Config::as_type_description()).unwrap();
+///
+///     assert_eq!(yaml, "# The hosts to connect to\nhosts:\n  - \"\"\n");
+/// ```
+pub fn render_to_yaml(desc: &TypeDescription) -> Result<String, std::fmt::Error> {
+    let mut renderer = YamlRenderer {
+        definitions: named_types(desc),
+        in_progress: vec![],
+    };
+
+    let mut lines = vec![];
+    if let Some(doc) = desc.doc() {
+        lines.extend(Line::comments(0, doc));
+        lines.push(Line {
+            comment: true,
+            ..Line::text(0, String::new())
+        });
+    }
+
+    for (idx, (label, node)) in renderer.alternatives(desc).into_iter().enumerate() {
+        if let Some(label) = label {
+            lines.extend(Line::comments(0, &label));
+        }
+
+        let mut node_lines = match &node {
+            Node::Scalar(value) => vec![Line::text(0, value.clone())],
+            Node::Mapping(entries) if entries.is_empty() => vec![Line::text(0, String::from("{}"))],
+            Node::Mapping(entries) => entries_lines(entries, 0),
+            Node::Sequence(_) => item_lines(&node, 0)
+                .into_iter()
+                .filter(|line| !line.text.is_empty())
+                .collect(),
+        };
+        if idx > 0 {
+            disable(&mut node_lines, 0);
+        }
+        lines.extend(node_lines);
+    }
+
+    let mut yaml = String::new();
+    for line in lines {
+        let mut text = match (line.comment, line.text.is_empty()) {
+            (true, true) => String::from("#"),
+            (true, false) => format!("# {}", line.text),
+            (false, _) => line.text,
+        };
+        let mut indent = line.indent;
+        if let Some(column) = line.disabled_at {
+            text = format!("# {}{text}", " ".repeat(indent - column));
+            indent = column;
+        }
+        writeln!(yaml, "{}{}", " ".repeat(indent), text.trim_end())?;
+    }
+
+    Ok(yaml)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        AsTypeDescription, EnumVariant, EnumVariantRepresentation, StructField, TypeDescription,
+        TypeEnumKind, TypeKind,
+    };
+
+    use super::render_to_yaml;
+
+    fn door(enum_kind: TypeEnumKind) -> TypeDescription {
+        TypeDescription::new(
+            String::from("Door"),
+            TypeKind::Enum(
+                enum_kind,
+                vec![
+                    EnumVariant::new(
+                        "Wooden",
+                        Some("A wooden door"),
+                        EnumVariantRepresentation::String("wooden".into()),
+                    ),
+                    EnumVariant::new(
+                        "Steel",
+                        None,
+                        EnumVariantRepresentation::Wrapped(Box::new(TypeDescription::new(
+                            String::from("Steel"),
+                            TypeKind::Struct(vec![StructField::new(
+                                "thickness",
+                                Some("In millimeters"),
+                                u8::as_type_description(),
+                                false,
+                            )]),
+                            None,
+                        ))),
+                    ),
+                ],
+            ),
+            None,
+        )
+    }
+
+    fn house(door: TypeDescription) -> TypeDescription {
+        TypeDescription::new(
+            String::from("House"),
+            TypeKind::Struct(vec![
+                StructField::new("doors", None, Vec::<bool>::as_type_description(), true),
+                StructField::new("front", Some("The front door"), door, false),
+            ]),
+            Some("A house"),
+        )
+    }
+
+    #[test]
+    fn render_tagged_enum() {
+        let yaml = render_to_yaml(&house(door(TypeEnumKind::Tagged("type".into())))).unwrap();
+
+        assert_eq!(
+            yaml,
+            r#"# A house
+#
+# doors:
+#   - false
+# The front door
+# Variant `Wooden`: A wooden door
+front:
+  type: wooden
+# Variant `Steel`
+# front:
+#   type: Steel
+#   # In millimeters
+#   thickness: 0
+"#
+        );
+    }
+
+    #[test]
+    fn render_untagged_enum() {
+        let yaml = render_to_yaml(&house(door(TypeEnumKind::Untagged))).unwrap();
+
+        assert!(yaml.contains(
+            "\nfront: null\n# Variant `Steel`\n# front:\n#   # In millimeters\n#   thickness: 0\n"
+        ));
+    }
+}
@@ -0,0 +1,1101 @@
+//
+//   This Source Code Form is subject to the terms of the Mozilla Public
+//   License, v. 2.0. If a copy of the MPL was not distributed with this
+//   file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+use std::{
+    cmp::Ordering,
+    collections::{BTreeMap, HashMap},
+    fmt::Display,
+    net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr},
+};
+
+use regex::Regex;
+use serde::{Deserialize, Deserializer};
+use serde_json::{Number, Value};
+
+use crate::{
+    registry::named_types, BytesEncoding, EnumVariantRepresentation, Sign, StringFormat,
+    TypeDescription, TypeEnumKind, TypeKind,
+};
+
+/// What went wrong when validating a value
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum ValidationErrorKind {
+    /// The value is of a different type than expected
+    InvalidType {
+        /// The kind of JSON value that was found instead
+        found: &'static str,
+    },
+    /// The value is a number that does not fit into the expected integer
+    OutOfRange,
+    /// An array of a fixed length or a character was given the wrong number of elements
+    InvalidLength {
+        /// The number of elements that were given
+        found: usize,
+    },
+    /// A required field was not given
+    MissingField,
+    /// An internally tagged enum was given without its tag field
+    MissingTag {
+        /// The name of the tag field
+        tag: String,
+    },
+    /// The tag of an enum did not name any of its variants
+    UnknownVariant {
+        /// The variant that was given
+        found: String,
+    },
+    /// The value of an untagged enum did not match any of its variants
+    NoMatchingVariant,
+    /// The number is smaller than the constrained minimum
+    TooSmall {
+        /// The smallest accepted number
+        min: Number,
+    },
+    /// The number is larger than the constrained maximum
+    TooLarge {
+        /// The largest accepted number
+        max: Number,
+    },
+    /// The string, array or map is shorter than the constrained minimum length
+    TooShort {
+        /// The smallest accepted length
+        min_len: usize,
+        /// The length that was given
+        found: usize,
+    },
+    /// The string, array or map is longer than the constrained maximum length
+    TooLong {
+        /// The largest accepted length
+        max_len: usize,
+        /// The length that was given
+        found: usize,
+    },
+    /// The string does not follow the format of its type
+    InvalidFormat {
+        /// The expected format
+        format: StringFormat,
+    },
+    /// The string is not valid in the encoding of its bytes
+    InvalidEncoding {
+        /// The expected encoding
+        encoding: BytesEncoding,
+    },
+    /// The string does not match the constrained pattern
+    PatternMismatch {
+        /// The regular expression that was not matched
+        pattern: String,
+    },
+    /// The constrained pattern is not a valid regular expression, so it cannot be checked
+    InvalidPattern {
+        /// The pattern that failed to compile
+        pattern: String,
+    },
+}
+
+impl Display for ValidationErrorKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ValidationErrorKind::InvalidType { found } => write!(f, "invalid type {found}"),
+            ValidationErrorKind::OutOfRange => write!(f, "number out of range"),
+            ValidationErrorKind::InvalidLength { found } => {
+                write!(f, "invalid length {found}")
+            }
+            ValidationErrorKind::MissingField => write!(f, "missing required field"),
+            ValidationErrorKind::MissingTag { tag } => write!(f, "missing tag field `{tag}`"),
+            ValidationErrorKind::UnknownVariant { found } => write!(f, "unknown variant `{found}`"),
+            ValidationErrorKind::NoMatchingVariant => write!(f, "no variant matched"),
+            ValidationErrorKind::TooSmall { min } => write!(f, "number smaller than {min}"),
+            ValidationErrorKind::TooLarge { max } => write!(f, "number larger than {max}"),
+            ValidationErrorKind::TooShort { min_len, found } => {
+                write!(f, "length {found} shorter than {min_len}")
+            }
+            ValidationErrorKind::TooLong { max_len, found } => {
+                write!(f, "length {found} longer than {max_len}")
+            }
+            ValidationErrorKind::InvalidFormat { format } => write!(f, "invalid {format}"),
+            ValidationErrorKind::InvalidEncoding { encoding } => {
+                write!(f, "invalid {encoding} encoding")
+            }
+            ValidationErrorKind::PatternMismatch { pattern } => {
+                write!(f, "does not match pattern `{pattern}`")
+            }
+            ValidationErrorKind::InvalidPattern { pattern } => {
+                write!(f, "invalid pattern `{pattern}`")
+            }
+        }
+    }
+}
+
+/// A single violation found while validating a value against a [`struct@TypeDescription`]
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ValidationError {
+    path: String,
+    kind: ValidationErrorKind,
+    expected: TypeKind,
+    doc: Option<String>,
+}
+
+impl ValidationError {
+    /// Get the path to the offending value, e.g. `clusters[2].cluster.server`
+    ///
+    /// The path is empty if the error concerns the value as a whole.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Get what went wrong
+    pub fn kind(&self) -> &ValidationErrorKind {
+        &self.kind
+    }
+
+    /// Get the kind that was expected at this path
+    pub fn expected(&self) -> &TypeKind {
+        &self.expected
+    }
+
+    /// Get the documentation of the field or type expected at this path
+    pub fn doc(&self) -> Option<&str> {
+        self.doc.as_deref()
+    }
+}
+
+impl Display for ValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.path.is_empty() {
+            write!(f, "{}", self.kind)
+        } else {
+            write!(f, "{}: {}", self.path, self.kind)
+        }
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
+fn json_type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+fn join_field(path: &str, name: &str) -> String {
+    if path.is_empty() {
+        name.to_string()
+    } else {
+        format!("{path}.{name}")
+    }
+}
+
+fn integer_fits(value: &Value, sign: &Sign, size: u8) -> bool {
+    let number = match (value.as_i64(), value.as_u64()) {
+        (Some(n), _) => i128::from(n),
+        (None, Some(n)) => i128::from(n),
+        (None, None) => return false,
+    };
+
+    // Every JSON integer fits into an `i128`, so 128 bit integers only need their sign checked
+    let size = u32::from(size);
+    match sign {
+        Sign::Signed => match size.checked_sub(1) {
+            None => number == 0,
+            Some(bits) if bits >= 127 => true,
+            Some(bits) => {
+                let max = (1i128 << bits) - 1;
+                (-max - 1..=max).contains(&number)
+            }
+        },
+        Sign::Unsigned if size >= 127 => number >= 0,
+        Sign::Unsigned => (0..1i128 << size).contains(&number),
+    }
+}
+
+/// Whether the string follows the given format
+///
+/// Only formats that the standard library can parse are checked, all others always match.
+fn format_matches(format: StringFormat, string: &str) -> bool {
+    match format {
+        StringFormat::SocketAddr => string.parse::<SocketAddr>().is_ok(),
+        StringFormat::Ip => string.parse::<IpAddr>().is_ok(),
+        StringFormat::Ipv4 => string.parse::<Ipv4Addr>().is_ok(),
+        StringFormat::Ipv6 => string.parse::<Ipv6Addr>().is_ok(),
+        StringFormat::Url
+        | StringFormat::Uuid
+        | StringFormat::Path
+        | StringFormat::ByteSize
+        | StringFormat::Duration
+        | StringFormat::DateTime => true,
+    }
+}
+
+/// Whether the string is a valid encoding of bytes, padding is optional for base64
+fn encoding_matches(encoding: BytesEncoding, string: &str) -> bool {
+    match encoding {
+        BytesEncoding::Raw => false,
+        BytesEncoding::Base64 => {
+            let data = string.trim_end_matches('=');
+            let padding = string.len() - data.len();
+            padding <= 2
+                && (padding == 0 || string.len() % 4 == 0)
+                && data.len() % 4 != 1
+                && data
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '/' | '-' | '_'))
+        }
+        BytesEncoding::Hex => {
+            string.len() % 2 == 0 && string.chars().all(|c| c.is_ascii_hexdigit())
+        }
+    }
+}
+
+fn compare_numbers(lhs: &Number, rhs: &Number) -> Option<Ordering> {
+    fn as_integer(number: &Number) -> Option<i128> {
+        number
+            .as_i64()
+            .map(i128::from)
+            .or_else(|| number.as_u64().map(i128::from))
+    }
+
+    match (as_integer(lhs), as_integer(rhs)) {
+        (Some(lhs), Some(rhs)) => Some(lhs.cmp(&rhs)),
+        _ => lhs.as_f64()?.partial_cmp(&rhs.as_f64()?),
+    }
+}
+
+struct Validator<'a> {
+    definitions: BTreeMap<&'a str, &'a TypeDescription>,
+    /// Compiled patterns by their source, `None` if they are not valid regular expressions
+    patterns: HashMap<String, Option<Regex>>,
+    errors: Vec<ValidationError>,
+}
+
+impl<'a> Validator<'a> {
+    fn error(
+        &mut self,
+        path: &str,
+        kind: ValidationErrorKind,
+        desc: &TypeDescription,
+        doc: Option<&str>,
+    ) {
+        self.errors.push(ValidationError {
+            path: path.to_string(),
+            kind,
+            expected: desc.kind().clone(),
+            doc: doc.or(desc.doc()).map(String::from),
+        });
+    }
+
+    fn invalid_type(
+        &mut self,
+        path: &str,
+        value: &Value,
+        desc: &TypeDescription,
+        doc: Option<&str>,
+    ) {
+        self.error(
+            path,
+            ValidationErrorKind::InvalidType {
+                found: json_type_name(value),
+            },
+            desc,
+            doc,
+        );
+    }
+
+    fn matches(&mut self, desc: &TypeDescription, value: &Value, path: &str) -> bool {
+        let mut validator = Validator {
+            definitions: self.definitions.clone(),
+            patterns: std::mem::take(&mut self.patterns),
+            errors: vec![],
+        };
+        validator.check(desc, None, value, path);
+        self.patterns = validator.patterns;
+        validator.errors.is_empty()
+    }
+
+    fn check_constraints(
+        &mut self,
+        desc: &TypeDescription,
+        doc: Option<&str>,
+        value: &Value,
+        path: &str,
+    ) {
+        let constraints = desc.constraints();
+
+        if let Value::Number(number) = value {
+            if let Some(min) = constraints.min() {
+                if compare_numbers(number, min) == Some(Ordering::Less) {
+                    self.error(
+                        path,
+                        ValidationErrorKind::TooSmall { min: min.clone() },
+                        desc,
+                        doc,
+                    );
+                }
+            }
+            if let Some(max) = constraints.max() {
+                if compare_numbers(number, max) == Some(Ordering::Greater) {
+                    self.error(
+                        path,
+                        ValidationErrorKind::TooLarge { max: max.clone() },
+                        desc,
+                        doc,
+                    );
+                }
+            }
+        }
+
+        let length = match value {
+            Value::String(string) => Some(string.chars().count()),
+            Value::Array(values) => Some(values.len()),
+            Value::Object(values) => Some(values.len()),
+            _ => None,
+        };
+        if let Some(found) = length {
+            if let Some(min_len) = constraints.min_len().filter(|min_len| found < *min_len) {
+                self.error(
+                    path,
+                    ValidationErrorKind::TooShort { min_len, found },
+                    desc,
+                    doc,
+                );
+            }
+            if let Some(max_len) = constraints.max_len().filter(|max_len| found > *max_len) {
+                self.error(
+                    path,
+                    ValidationErrorKind::TooLong { max_len, found },
+                    desc,
+                    doc,
+                );
+            }
+        }
+
+        if let (Some(pattern), Value::String(string)) = (constraints.pattern(), value) {
+            let regex = self
+                .patterns
+                .entry(pattern.to_string())
+                .or_insert_with(|| Regex::new(pattern).ok());
+            let kind = match regex {
+                Some(regex) if regex.is_match(string) => None,
+                Some(_) => Some(ValidationErrorKind::PatternMismatch {
+                    pattern: pattern.to_string(),
+                }),
+                None => Some(ValidationErrorKind::InvalidPattern {
+                    pattern: pattern.to_string(),
+                }),
+            };
+            if let Some(kind) = kind {
+                self.error(path, kind, desc, doc);
+            }
+        }
+    }
+
+    fn check(&mut self, desc: &TypeDescription, doc: Option<&str>, value: &Value, path: &str) {
+        self.check_constraints(desc, doc, value, path);
+
+        match desc.kind() {
+            TypeKind::Reference(name) => {
+                if let Some(definition) = self.definitions.get(name.as_str()).copied() {
+                    self.check(definition, doc, value, path);
+                }
+            }
+            TypeKind::Unit => {
+                if !value.is_null() {
+                    self.invalid_type(path, value, desc, doc);
+                }
+            }
+            TypeKind::Any => (),
+            TypeKind::Bool => {
+                if !value.is_boolean() {
+                    self.invalid_type(path, value, desc, doc);
+                }
+            }
+            TypeKind::Char => match value {
+                Value::String(string) => {
+                    let found = string.chars().count();
+                    if found != 1 {
+                        self.error(
+                            path,
+                            ValidationErrorKind::InvalidLength { found },
+                            desc,
+                            doc,
+                        );
+                    }
+                }
+                _ => self.invalid_type(path, value, desc, doc),
+            },
+            TypeKind::Bytes {
+                encoding: BytesEncoding::Raw,
+            } => match value {
+                Value::Array(values) => {
+                    let byte = <u8 as crate::AsTypeDescription>::as_type_description();
+                    for (idx, value) in values.iter().enumerate() {
+                        self.check(&byte, None, value, &format!("{path}[{idx}]"));
+                    }
+                }
+                _ => self.invalid_type(path, value, desc, doc),
+            },
+            TypeKind::Bytes { encoding } => match value {
+                Value::String(string) => {
+                    if !encoding_matches(*encoding, string) {
+                        self.error(
+                            path,
+                            ValidationErrorKind::InvalidEncoding {
+                                encoding: *encoding,
+                            },
+                            desc,
+                            doc,
+                        );
+                    }
+                }
+                _ => self.invalid_type(path, value, desc, doc),
+            },
+            TypeKind::Integer { sign, size } => {
+                if !value.is_i64() && !value.is_u64() {
+                    self.invalid_type(path, value, desc, doc);
+                } else if !integer_fits(value, sign, *size) {
+                    self.error(path, ValidationErrorKind::OutOfRange, desc, doc);
+                }
+            }
+            TypeKind::Float { .. } => {
+                if !value.is_number() {
+                    self.invalid_type(path, value, desc, doc);
+                }
+            }
+            TypeKind::String => {
+                if !value.is_string() {
+                    self.invalid_type(path, value, desc, doc);
+                }
+            }
+            TypeKind::FormattedString(format) => match value {
+                Value::String(string) => {
+                    if !format_matches(*format, string) {
+                        self.error(
+                            path,
+                            ValidationErrorKind::InvalidFormat { format: *format },
+                            desc,
+                            doc,
+                        );
+                    }
+                }
+                _ => self.invalid_type(path, value, desc, doc),
+            },
+            TypeKind::Optional(inner) => {
+                if !value.is_null() {
+                    self.check(inner, doc, value, path);
+                }
+            }
+            TypeKind::Wrapped(wrapped) => {
+                self.check(wrapped, doc.or(desc.doc()), value, path);
+            }
+            TypeKind::Array(element) => match value {
+                Value::Array(values) => {
+                    for (idx, value) in values.iter().enumerate() {
+                        self.check(element, None, value, &format!("{path}[{idx}]"));
+                    }
+                }
+                _ => self.invalid_type(path, value, desc, doc),
+            },
+            TypeKind::FixedArray { element, length } => match value {
+                Value::Array(values) => {
+                    if values.len() != *length {
+                        self.error(
+                            path,
+                            ValidationErrorKind::InvalidLength {
+                                found: values.len(),
+                            },
+                            desc,
+                            doc,
+                        );
+                    }
+                    for (idx, value) in values.iter().enumerate() {
+                        self.check(element, None, value, &format!("{path}[{idx}]"));
+                    }
+                }
+                _ => self.invalid_type(path, value, desc, doc),
+            },
+            TypeKind::Tuple(elements) => match value {
+                Value::Array(values) => {
+                    if values.len() != elements.len() {
+                        self.error(
+                            path,
+                            ValidationErrorKind::InvalidLength {
+                                found: values.len(),
+                            },
+                            desc,
+                            doc,
+                        );
+                    }
+                    for (idx, (element, value)) in elements.iter().zip(values).enumerate() {
+                        self.check(element, None, value, &format!("{path}[{idx}]"));
+                    }
+                }
+                _ => self.invalid_type(path, value, desc, doc),
+            },
+            TypeKind::HashMap {
+                key: _,
+                value: kind,
+            } => match value {
+                Value::Object(values) => {
+                    for (key, value) in values {
+                        self.check(kind, None, value, &join_field(path, key));
+                    }
+                }
+                _ => self.invalid_type(path, value, desc, doc),
+            },
+            TypeKind::Struct(fields) => match value {
+                Value::Object(values) => {
+                    for field in fields {
+                        let field_path = join_field(path, field.name());
+                        match values.get(field.name()) {
+                            Some(value) => {
+                                self.check(field.kind(), field.doc(), value, &field_path)
+                            }
+                            None if field.optional() => (),
+                            None => self.error(
+                                &field_path,
+                                ValidationErrorKind::MissingField,
+                                field.kind(),
+                                field.doc(),
+                            ),
+                        }
+                    }
+                }
+                _ => self.invalid_type(path, value, desc, doc),
+            },
+            TypeKind::Enum(TypeEnumKind::External, variants) => match value {
+                Value::String(name) => {
+                    let is_unit_variant = variants.iter().any(|variant| {
+                        matches!(variant.repr(), EnumVariantRepresentation::String(repr) if repr == name)
+                    });
+
+                    if !is_unit_variant {
+                        self.error(
+                            path,
+                            ValidationErrorKind::UnknownVariant {
+                                found: name.to_string(),
+                            },
+                            desc,
+                            doc,
+                        );
+                    }
+                }
+                Value::Object(values) if values.len() == 1 => {
+                    let (name, content) = values.iter().next().unwrap();
+                    let variant = variants.iter().find(|variant| variant.name() == name);
+
+                    match variant.map(|variant| variant.repr()) {
+                        Some(EnumVariantRepresentation::String(_)) if content.is_null() => (),
+                        Some(EnumVariantRepresentation::String(_)) => {
+                            self.invalid_type(&join_field(path, name), content, desc, doc)
+                        }
+                        Some(EnumVariantRepresentation::Wrapped(wrapped)) => {
+                            self.check(wrapped, None, content, &join_field(path, name))
+                        }
+                        None => self.error(
+                            path,
+                            ValidationErrorKind::UnknownVariant {
+                                found: name.to_string(),
+                            },
+                            desc,
+                            doc,
+                        ),
+                    }
+                }
+                _ => self.invalid_type(path, value, desc, doc),
+            },
+            TypeKind::Enum(
+                enum_kind @ (TypeEnumKind::Tagged(tag) | TypeEnumKind::Adjacent { tag, .. }),
+                variants,
+            ) => {
+                let Value::Object(values) = value else {
+                    return self.invalid_type(path, value, desc, doc);
+                };
+
+                let Some(tag_value) = values.get(tag.as_ref()) else {
+                    return self.error(
+                        path,
+                        ValidationErrorKind::MissingTag {
+                            tag: tag.to_string(),
+                        },
+                        desc,
+                        doc,
+                    );
+                };
+
+                let tag_path = join_field(path, tag);
+                let Some(tag_value) = tag_value.as_str() else {
+                    return self.invalid_type(&tag_path, tag_value, desc, doc);
+                };
+
+                let variant = variants.iter().find(|variant| match variant.repr() {
+                    EnumVariantRepresentation::String(repr) => repr == tag_value,
+                    EnumVariantRepresentation::Wrapped(_) => variant.name() == tag_value,
+                });
+
+                match (variant.map(|variant| variant.repr()), enum_kind) {
+                    (Some(EnumVariantRepresentation::String(_)), _) => (),
+                    (
+                        Some(EnumVariantRepresentation::Wrapped(wrapped)),
+                        TypeEnumKind::Adjacent { content, .. },
+                    ) => {
+                        let content_path = join_field(path, content);
+                        match values.get(content.as_ref()) {
+                            Some(value) => self.check(wrapped, None, value, &content_path),
+                            None => self.error(
+                                &content_path,
+                                ValidationErrorKind::MissingField,
+                                wrapped,
+                                None,
+                            ),
+                        }
+                    }
+                    (Some(EnumVariantRepresentation::Wrapped(wrapped)), _) => {
+                        self.check(wrapped, None, value, path)
+                    }
+                    (None, _) => self.error(
+                        &tag_path,
+                        ValidationErrorKind::UnknownVariant {
+                            found: tag_value.to_string(),
+                        },
+                        desc,
+                        doc,
+                    ),
+                }
+            }
+            TypeKind::Enum(TypeEnumKind::Untagged, variants) => {
+                let matched = variants.iter().any(|variant| match variant.repr() {
+                    // Like serde, untagged unit variants are written as `null` instead of their name
+                    EnumVariantRepresentation::String(_) => value.is_null(),
+                    EnumVariantRepresentation::Wrapped(wrapped) => {
+                        self.matches(wrapped, value, path)
+                    }
+                });
+
+                if !matched {
+                    self.error(path, ValidationErrorKind::NoMatchingVariant, desc, doc);
+                }
+            }
+        }
+    }
+}
+
+/// Validate a JSON value against a [`struct@TypeDescription`]
+///
+/// Contrary to deserializing, this does not stop at the first problem but collects every
+/// violation, each with the path to the offending value. An empty list means the value is valid.
+///
+/// # Example
+///
+/// ```rust
+///     use type_description::AsTypeDescription;
+///     use type_description::validate::validate_value;
+///
+///     let desc = Vec::<u8>::as_type_description();
+///     let errors = validate_value(&desc, &serde_json::json!([1, 300, "a"]));
+///
+///     assert_eq!(errors.len(), 2);
+///     assert_eq!(errors[0].path(), "[1]");
+///     assert_eq!(errors[1].to_string(), "[2]: invalid type string");
+/// ```
+pub fn validate_value(desc: &TypeDescription, value: &Value) -> Vec<ValidationError> {
+    let mut validator = Validator {
+        definitions: named_types(desc),
+        patterns: HashMap::new(),
+        errors: vec![],
+    };
+    validator.check(desc, None, value, "");
+    validator.errors
+}
+
+/// Validate the input of any [`Deserializer`] against a [`struct@TypeDescription`]
+///
+/// The input is first read in as a [`serde_json::Value`], which means that only syntax errors of
+/// the underlying format are returned as an `Err`. See [`validate_value`] for the validation
+/// itself.
+pub fn validate<'de, D: Deserializer<'de>>(
+    desc: &TypeDescription,
+    deserializer: D,
+) -> Result<Vec<ValidationError>, D::Error> {
+    let value = Value::deserialize(deserializer)?;
+    Ok(validate_value(desc, &value))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use serde_json::json;
+
+    use crate::{
+        AsTypeDescription, BytesEncoding, Constraints, EnumVariant, EnumVariantRepresentation,
+        Sign, StructField, TypeDescription, TypeEnumKind, TypeKind,
+    };
+
+    use super::{validate, validate_value, ValidationErrorKind};
+
+    fn cluster_config() -> TypeDescription {
+        let cluster = TypeDescription::new(
+            String::from("Cluster"),
+            TypeKind::Struct(vec![
+                StructField::new(
+                    "server",
+                    Some("The server to connect to"),
+                    String::as_type_description(),
+                    false,
+                ),
+                StructField::new("port", None, u16::as_type_description(), true),
+            ]),
+            None,
+        );
+
+        TypeDescription::new(
+            String::from("Config"),
+            TypeKind::Struct(vec![StructField::new(
+                "clusters",
+                None,
+                TypeDescription::new(
+                    String::from("Array of 'Cluster's"),
+                    TypeKind::Array(Box::new(cluster)),
+                    None,
+                ),
+                false,
+            )]),
+            None,
+        )
+    }
+
+    #[test]
+    fn reports_every_error() {
+        let value = json!({
+            "clusters": [
+                { "server": "a" },
+                { "port": 70000 },
+                { "server": 1, "port": -1 },
+            ]
+        });
+
+        let errors = validate_value(&cluster_config(), &value);
+
+        let paths = errors.iter().map(|e| e.path()).collect::<Vec<_>>();
+        assert_eq!(
+            paths,
+            [
+                "clusters[1].server",
+                "clusters[1].port",
+                "clusters[2].server",
+                "clusters[2].port"
+            ]
+        );
+        assert_eq!(errors[0].kind(), &ValidationErrorKind::MissingField);
+        assert_eq!(errors[0].doc(), Some("The server to connect to"));
+        assert_eq!(errors[1].kind(), &ValidationErrorKind::OutOfRange);
+    }
+
+    #[test]
+    fn validates_recursive_newtypes() {
+        let tree = TypeDescription::new(
+            String::from("Tree"),
+            TypeKind::Wrapped(Box::new(TypeDescription::new(
+                String::from("Array of 'Tree's"),
+                TypeKind::Array(Box::new(TypeDescription::reference("Tree"))),
+                None,
+            ))),
+            None,
+        );
+        let desc = TypeDescription::builder("Holder")
+            .field("tree", tree)
+            .build();
+
+        assert!(validate_value(&desc, &json!({ "tree": [[], [[]]] })).is_empty());
+
+        let errors = validate_value(&desc, &json!({ "tree": [[1]] }));
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].path(), "tree[0][0]");
+    }
+
+    #[test]
+    fn validates_unit_and_any() {
+        let desc = TypeDescription::builder("Plugin")
+            .field("marker", <()>::as_type_description())
+            .field("settings", serde_json::Value::as_type_description())
+            .build();
+
+        assert!(validate_value(&desc, &json!({ "marker": null, "settings": [1, "a"] })).is_empty());
+
+        let errors = validate_value(&desc, &json!({ "marker": 1, "settings": null }));
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].path(), "marker");
+    }
+
+    #[test]
+    fn validates_bytes_and_char() {
+        let desc = TypeDescription::builder("Key")
+            .field("initial", char::as_type_description())
+            .field("raw", TypeDescription::bytes(BytesEncoding::Raw))
+            .field("secret", TypeDescription::bytes(BytesEncoding::Base64))
+            .field("digest", TypeDescription::bytes(BytesEncoding::Hex))
+            .build();
+
+        let valid = json!({ "initial": "é", "raw": [0, 255], "secret": "aGk=", "digest": "0aF9" });
+        assert!(validate_value(&desc, &valid).is_empty());
+
+        let invalid = json!({ "initial": "ab", "raw": [256], "secret": "a", "digest": "abc" });
+        let errors = validate_value(&desc, &invalid);
+        let paths = errors.iter().map(|error| error.path()).collect::<Vec<_>>();
+        assert_eq!(paths, vec!["initial", "raw[0]", "secret", "digest"]);
+        assert_eq!(
+            errors[3].kind(),
+            &ValidationErrorKind::InvalidEncoding {
+                encoding: BytesEncoding::Hex
+            }
+        );
+    }
+
+    #[test]
+    fn validates_tagged_enums() {
+        let desc = TypeDescription::new(
+            String::from("Auth"),
+            TypeKind::Enum(
+                TypeEnumKind::Tagged(Cow::Borrowed("type")),
+                vec![
+                    EnumVariant::new(
+                        "None",
+                        None,
+                        EnumVariantRepresentation::String(Cow::Borrowed("None")),
+                    ),
+                    EnumVariant::new(
+                        "Token",
+                        None,
+                        EnumVariantRepresentation::Wrapped(Box::new(TypeDescription::new(
+                            String::from("Token"),
+                            TypeKind::Struct(vec![StructField::new(
+                                "token",
+                                None,
+                                String::as_type_description(),
+                                false,
+                            )]),
+                            None,
+                        ))),
+                    ),
+                ],
+            ),
+            None,
+        );
+
+        assert!(validate_value(&desc, &json!({ "type": "None" })).is_empty());
+        assert!(validate_value(&desc, &json!({ "type": "Token", "token": "a" })).is_empty());
+
+        let errors = validate_value(&desc, &json!({ "type": "Token" }));
+        assert_eq!(errors[0].path(), "token");
+
+        let errors = validate_value(&desc, &json!({ "type": "Password" }));
+        assert_eq!(
+            errors[0].kind(),
+            &ValidationErrorKind::UnknownVariant {
+                found: String::from("Password")
+            }
+        );
+    }
+
+    #[test]
+    fn validates_externally_and_adjacently_tagged_enums() {
+        let variants = vec![
+            EnumVariant::new(
+                "None",
+                None,
+                EnumVariantRepresentation::String(Cow::Borrowed("None")),
+            ),
+            EnumVariant::new(
+                "Token",
+                None,
+                EnumVariantRepresentation::Wrapped(Box::new(String::as_type_description())),
+            ),
+        ];
+        let external = TypeDescription::new(
+            String::from("Auth"),
+            TypeKind::Enum(TypeEnumKind::External, variants.clone()),
+            None,
+        );
+        let adjacent = TypeDescription::new(
+            String::from("Auth"),
+            TypeKind::Enum(
+                TypeEnumKind::Adjacent {
+                    tag: Cow::Borrowed("t"),
+                    content: Cow::Borrowed("c"),
+                },
+                variants,
+            ),
+            None,
+        );
+
+        assert!(validate_value(&external, &json!("None")).is_empty());
+        assert!(validate_value(&external, &json!({ "Token": "a" })).is_empty());
+        assert_eq!(
+            validate_value(&external, &json!({ "Token": 1 }))[0].path(),
+            "Token"
+        );
+
+        assert!(validate_value(&adjacent, &json!({ "t": "None" })).is_empty());
+        assert!(validate_value(&adjacent, &json!({ "t": "Token", "c": "a" })).is_empty());
+        assert_eq!(
+            validate_value(&adjacent, &json!({ "t": "Token" }))[0].path(),
+            "c"
+        );
+    }
+
+    #[test]
+    fn validates_tuples() {
+        let desc = <(u16, u16)>::as_type_description();
+
+        assert!(validate_value(&desc, &json!([80, 8080])).is_empty());
+
+        let errors = validate_value(&desc, &json!([80, "a", 1]));
+        assert_eq!(
+            errors[0].kind(),
+            &ValidationErrorKind::InvalidLength { found: 3 }
+        );
+        assert_eq!(errors[1].path(), "[1]");
+    }
+
+    #[test]
+    fn validates_constraints() {
+        let port = u16::as_type_description()
+            .with_constraints(Constraints::new().with_min(1).with_max(1024));
+        let name = String::as_type_description()
+            .with_constraints(Constraints::new().with_min_len(2).with_pattern("^[a-z]+$"));
+
+        assert!(validate_value(&port, &json!(80)).is_empty());
+        assert_eq!(
+            validate_value(&port, &json!(0))[0].kind(),
+            &ValidationErrorKind::TooSmall { min: 1.into() }
+        );
+        assert_eq!(
+            validate_value(&port, &json!(8080))[0].kind(),
+            &ValidationErrorKind::TooLarge { max: 1024.into() }
+        );
+
+        assert!(validate_value(&name, &json!("abc")).is_empty());
+        let errors = validate_value(&name, &json!("A"));
+        assert_eq!(
+            errors.iter().map(|error| error.kind()).collect::<Vec<_>>(),
+            vec![
+                &ValidationErrorKind::TooShort {
+                    min_len: 2,
+                    found: 1
+                },
+                &ValidationErrorKind::PatternMismatch {
+                    pattern: String::from("^[a-z]+$")
+                },
+            ]
+        );
+
+        let broken =
+            String::as_type_description().with_constraints(Constraints::new().with_pattern("[a-z"));
+        assert_eq!(
+            validate_value(&broken, &json!("abc"))[0].kind(),
+            &ValidationErrorKind::InvalidPattern {
+                pattern: String::from("[a-z")
+            }
+        );
+    }
+
+    #[test]
+    fn validates_integer_sizes() {
+        let integer = |sign, size| {
+            TypeDescription::new_owned("Integer", TypeKind::Integer { sign, size }, None)
+        };
+
+        let errors = [
+            (Sign::Unsigned, 0, json!(1)),
+            (Sign::Signed, 0, json!(-1)),
+            (Sign::Signed, 8, json!(128)),
+            (Sign::Unsigned, 128, json!(-1)),
+            (Sign::Signed, 64, json!(1.5)),
+        ]
+        .into_iter()
+        .map(|(sign, size, value)| {
+            validate_value(&integer(sign, size), &value)[0]
+                .kind()
+                .clone()
+        })
+        .collect::<Vec<_>>();
+
+        assert_eq!(
+            errors,
+            vec![
+                ValidationErrorKind::OutOfRange,
+                ValidationErrorKind::OutOfRange,
+                ValidationErrorKind::OutOfRange,
+                ValidationErrorKind::OutOfRange,
+                ValidationErrorKind::InvalidType { found: "number" },
+            ]
+        );
+        assert!(validate_value(&integer(Sign::Signed, 128), &json!(i64::MIN)).is_empty());
+        assert!(validate_value(&integer(Sign::Unsigned, 128), &json!(u64::MAX)).is_empty());
+    }
+
+    #[test]
+    fn validates_untagged_unit_variants_like_serde() {
+        #[derive(Debug, serde::Deserialize)]
+        #[serde(untagged)]
+        enum Level {
+            Off,
+            Fixed(u8),
+        }
+
+        let desc = TypeDescription::new(
+            String::from("Level"),
+            TypeKind::Enum(
+                TypeEnumKind::Untagged,
+                vec![
+                    EnumVariant::new(
+                        "Off",
+                        None,
+                        EnumVariantRepresentation::String(Cow::Borrowed("Off")),
+                    ),
+                    EnumVariant::new(
+                        "Fixed",
+                        None,
+                        EnumVariantRepresentation::Wrapped(Box::new(u8::as_type_description())),
+                    ),
+                ],
+            ),
+            None,
+        );
+
+        for value in [json!(null), json!("Off"), json!(3), json!(300)] {
+            assert_eq!(
+                validate_value(&desc, &value).is_empty(),
+                serde_json::from_value::<Level>(value.clone()).is_ok(),
+                "{value}"
+            );
+        }
+    }
+
+    #[test]
+    fn validates_string_formats() {
+        let desc = std::net::SocketAddr::as_type_description();
+
+        assert!(validate_value(&desc, &json!("127.0.0.1:8080")).is_empty());
+        assert!(validate_value(&desc, &json!("[::1]:8080")).is_empty());
+
+        let errors = validate_value(&desc, &json!("localhost"));
+        assert_eq!(errors[0].to_string(), "invalid socket-addr");
+    }
+
+    #[test]
+    fn validates_from_deserializer() {
+        let mut deserializer = serde_json::Deserializer::from_str(r#"{ "clusters": {} }"#);
+        let errors = validate(&cluster_config(), &mut deserializer).unwrap();
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].to_string(), "clusters: invalid type object");
+    }
+}
@@ -0,0 +1,415 @@
+//
+//   This Source Code Form is subject to the terms of the Mozilla Public
+//   License, v. 2.0. If a copy of the MPL was not distributed with this
+//   file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+use std::fmt::Display;
+
+use crate::{
+    DescriptionPath, EnumVariant, EnumVariantRepresentation, StructField, TypeDescription, TypeKind,
+};
+
+/// A single step from a description to one of the descriptions it contains
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub enum PathSegment {
+    /// The struct field with the given name, written as `.name`
+    Field(String),
+    /// The payload of the enum variant with the given name, written as `.name`
+    Variant(String),
+    /// Any element of an array, written as `[]`
+    Element,
+    /// The tuple element at the given position, written as `[0]`
+    Index(usize),
+    /// Any key of a map, written as `.<key>`
+    Key,
+    /// Any value of a map, written as `.*`
+    Value,
+}
+
+impl Display for PathSegment {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PathSegment::Field(name) | PathSegment::Variant(name) => write!(f, ".{name}"),
+            PathSegment::Element => write!(f, "[]"),
+            PathSegment::Index(idx) => write!(f, "[{idx}]"),
+            PathSegment::Key => write!(f, ".<key>"),
+            PathSegment::Value => write!(f, ".*"),
+        }
+    }
+}
+
+/// Format a path like `clusters[].cluster.server`
+pub fn display_path(path: &[PathSegment]) -> String {
+    DescriptionPath::from(path).to_string()
+}
+
+/// Callbacks for walking a [`struct@TypeDescription`] tree with [`walk`]
+///
+/// Every method receives the path from the root to the visited description, see
+/// [`display_path`] to format it. All methods do nothing by default.
+pub trait Visitor<'a> {
+    /// Called when reaching a description, before any of its children
+    ///
+    /// Return `false` to skip the children, [`Visitor::leave`] is not called in that case.
+    fn enter(&mut self, path: &[PathSegment], desc: &'a TypeDescription) -> bool {
+        let _ = (path, desc);
+        true
+    }
+
+    /// Called after all children of a description have been visited
+    fn leave(&mut self, path: &[PathSegment], desc: &'a TypeDescription) {
+        let _ = (path, desc);
+    }
+
+    /// Called for every struct field, before its kind is entered at the same path
+    fn visit_field(&mut self, path: &[PathSegment], field: &'a StructField) {
+        let _ = (path, field);
+    }
+
+    /// Called for every enum variant, before its payload is entered at the same path
+    fn visit_variant(&mut self, path: &[PathSegment], variant: &'a EnumVariant) {
+        let _ = (path, variant);
+    }
+}
+
+fn walk_child<'a, V: Visitor<'a> + ?Sized>(
+    segment: PathSegment,
+    desc: &'a TypeDescription,
+    visitor: &mut V,
+    path: &mut Vec<PathSegment>,
+) {
+    path.push(segment);
+    walk_at(desc, visitor, path);
+    path.pop();
+}
+
+fn walk_at<'a, V: Visitor<'a> + ?Sized>(
+    desc: &'a TypeDescription,
+    visitor: &mut V,
+    path: &mut Vec<PathSegment>,
+) {
+    if !visitor.enter(path, desc) {
+        return;
+    }
+
+    match desc.kind() {
+        TypeKind::Unit
+        | TypeKind::Any
+        | TypeKind::Bool
+        | TypeKind::Char
+        | TypeKind::Bytes { .. }
+        | TypeKind::Integer { .. }
+        | TypeKind::Float { .. }
+        | TypeKind::String
+        | TypeKind::FormattedString(_)
+        | TypeKind::Reference(_) => (),
+        TypeKind::Wrapped(inner) | TypeKind::Optional(inner) => walk_at(inner, visitor, path),
+        TypeKind::Array(element) | TypeKind::FixedArray { element, .. } => {
+            walk_child(PathSegment::Element, element, visitor, path)
+        }
+        TypeKind::Tuple(elements) => {
+            for (idx, element) in elements.iter().enumerate() {
+                walk_child(PathSegment::Index(idx), element, visitor, path);
+            }
+        }
+        TypeKind::HashMap { key, value } => {
+            walk_child(PathSegment::Key, key, visitor, path);
+            walk_child(PathSegment::Value, value, visitor, path);
+        }
+        TypeKind::Struct(fields) => {
+            for field in fields {
+                path.push(PathSegment::Field(field.name().to_string()));
+                visitor.visit_field(path, field);
+                path.pop();
+                walk_child(
+                    PathSegment::Field(field.name().to_string()),
+                    field.kind(),
+                    visitor,
+                    path,
+                );
+            }
+        }
+        TypeKind::Enum(_, variants) => {
+            for variant in variants {
+                path.push(PathSegment::Variant(variant.name().to_string()));
+                visitor.visit_variant(path, variant);
+                path.pop();
+                if let EnumVariantRepresentation::Wrapped(payload) = variant.repr() {
+                    walk_child(
+                        PathSegment::Variant(variant.name().to_string()),
+                        payload,
+                        visitor,
+                        path,
+                    );
+                }
+            }
+        }
+    }
+
+    visitor.leave(path, desc);
+}
+
+/// Walk a [`struct@TypeDescription`] depth first, calling the [`Visitor`] on every description
+///
+/// Children are visited in order, map keys before map values. [`TypeKind::Reference`]s are not
+/// followed, which keeps recursive types from being walked forever. Wrapped and optional types
+/// share the path of their wrapper.
+///
+/// # Example
+///
+/// ```rust
+///     use type_description::{AsTypeDescription, TypeDescription, TypeKind};
+///     use type_description::visit::{display_path, walk, PathSegment, Visitor};
+///
+///     struct Strings(Vec<String>);
+///
+///     impl<'a> Visitor<'a> for Strings {
+///         fn enter(&mut self, path: &[PathSegment], desc: &'a TypeDescription) -> bool {
+///             if matches!(desc.kind(), TypeKind::String | TypeKind::FormattedString(_)) {
+///                 self.0.push(display_path(path));
+///             }
+///             true
+///         }
+///     }
+///
+///     let mut strings = Strings(vec![]);
+///     walk(&Vec::<(u8, String)>::as_type_description(), &mut strings);
+///
+///     assert_eq!(strings.0, ["[][1]"]);
+/// ```
+pub fn walk<'a, V: Visitor<'a> + ?Sized>(desc: &'a TypeDescription, visitor: &mut V) {
+    walk_at(desc, visitor, &mut vec![]);
+}
+
+/// Rewrites a [`struct@TypeDescription`] tree, see [`Fold::fold_description`]
+///
+/// By default every method rebuilds its input from its folded children. Override a method to
+/// change what it returns, and call the matching `fold_*_children` function to keep folding
+/// below it.
+pub trait Fold {
+    /// Rewrite a description
+    fn fold_description(&mut self, desc: TypeDescription) -> TypeDescription {
+        fold_description_children(self, desc)
+    }
+
+    /// Rewrite a struct field
+    fn fold_field(&mut self, field: StructField) -> StructField {
+        fold_field_children(self, field)
+    }
+
+    /// Rewrite an enum variant
+    fn fold_variant(&mut self, variant: EnumVariant) -> EnumVariant {
+        fold_variant_children(self, variant)
+    }
+}
+
+fn fold_boxed<F: Fold + ?Sized>(
+    folder: &mut F,
+    desc: Box<TypeDescription>,
+) -> Box<TypeDescription> {
+    Box::new(folder.fold_description(*desc))
+}
+
+/// Fold the kind of a description, leaving the rest of it as is
+pub fn fold_description_children<F: Fold + ?Sized>(
+    folder: &mut F,
+    mut desc: TypeDescription,
+) -> TypeDescription {
+    desc.kind = match desc.kind {
+        kind @ (TypeKind::Unit
+        | TypeKind::Any
+        | TypeKind::Bool
+        | TypeKind::Char
+        | TypeKind::Bytes { .. }
+        | TypeKind::Integer { .. }
+        | TypeKind::Float { .. }
+        | TypeKind::String
+        | TypeKind::FormattedString(_)
+        | TypeKind::Reference(_)) => kind,
+        TypeKind::Wrapped(inner) => TypeKind::Wrapped(fold_boxed(folder, inner)),
+        TypeKind::Optional(inner) => TypeKind::Optional(fold_boxed(folder, inner)),
+        TypeKind::Array(element) => TypeKind::Array(fold_boxed(folder, element)),
+        TypeKind::FixedArray { element, length } => TypeKind::FixedArray {
+            element: fold_boxed(folder, element),
+            length,
+        },
+        TypeKind::Tuple(elements) => TypeKind::Tuple(
+            elements
+                .into_iter()
+                .map(|element| folder.fold_description(element))
+                .collect(),
+        ),
+        TypeKind::HashMap { key, value } => TypeKind::HashMap {
+            key: fold_boxed(folder, key),
+            value: fold_boxed(folder, value),
+        },
+        TypeKind::Struct(fields) => TypeKind::Struct(
+            fields
+                .into_iter()
+                .map(|field| folder.fold_field(field))
+                .collect(),
+        ),
+        TypeKind::Enum(enum_kind, variants) => TypeKind::Enum(
+            enum_kind,
+            variants
+                .into_iter()
+                .map(|variant| folder.fold_variant(variant))
+                .collect(),
+        ),
+    };
+    desc
+}
+
+/// Fold the kind of a struct field, leaving the rest of it as is
+pub fn fold_field_children<F: Fold + ?Sized>(
+    folder: &mut F,
+    mut field: StructField,
+) -> StructField {
+    field.kind = folder.fold_description(field.kind);
+    field
+}
+
+/// Fold the payload of an enum variant, leaving the rest of it as is
+pub fn fold_variant_children<F: Fold + ?Sized>(
+    folder: &mut F,
+    mut variant: EnumVariant,
+) -> EnumVariant {
+    if let EnumVariantRepresentation::Wrapped(payload) = variant.repr {
+        variant.repr = EnumVariantRepresentation::Wrapped(fold_boxed(folder, payload));
+    }
+    variant
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        AsTypeDescription, EnumVariant, EnumVariantRepresentation, StructField, TypeDescription,
+        TypeKind,
+    };
+
+    use super::{display_path, fold_description_children, walk, Fold, PathSegment, Visitor};
+
+    fn config() -> TypeDescription {
+        TypeDescription::builder("Config")
+            .documented_field(
+                "clusters",
+                "The clusters to connect to",
+                TypeDescription::array_of(
+                    TypeDescription::builder("Cluster")
+                        .doc("A cluster")
+                        .field("server", String::as_type_description())
+                        .build(),
+                ),
+            )
+            .field(
+                "auth",
+                TypeDescription::enum_builder("Auth")
+                    .unit_variant("Anonymous")
+                    .variant(
+                        "Token",
+                        TypeDescription::map_of(
+                            String::as_type_description(),
+                            u8::as_type_description(),
+                        ),
+                    )
+                    .build(),
+            )
+            .build()
+    }
+
+    #[derive(Default)]
+    struct Events(Vec<String>);
+
+    impl<'a> Visitor<'a> for Events {
+        fn enter(&mut self, path: &[PathSegment], desc: &'a TypeDescription) -> bool {
+            self.0
+                .push(format!("enter {} {}", display_path(path), desc.name()));
+            !matches!(desc.kind(), TypeKind::HashMap { .. })
+        }
+
+        fn leave(&mut self, path: &[PathSegment], desc: &'a TypeDescription) {
+            self.0
+                .push(format!("leave {} {}", display_path(path), desc.name()));
+        }
+
+        fn visit_field(&mut self, path: &[PathSegment], field: &'a StructField) {
+            self.0
+                .push(format!("field {} {:?}", display_path(path), field.doc()));
+        }
+
+        fn visit_variant(&mut self, path: &[PathSegment], variant: &'a EnumVariant) {
+            self.0
+                .push(format!("variant {} {}", display_path(path), variant.name()));
+        }
+    }
+
+    #[test]
+    fn walk_tracks_paths() {
+        let desc = config();
+        let mut events = Events::default();
+        walk(&desc, &mut events);
+
+        assert_eq!(
+            events.0,
+            [
+                "enter  Config",
+                "field clusters Some(\"The clusters to connect to\")",
+                "enter clusters Array of 'Cluster's",
+                "enter clusters[] Cluster",
+                "field clusters[].server None",
+                "enter clusters[].server String",
+                "leave clusters[].server String",
+                "leave clusters[] Cluster",
+                "leave clusters Array of 'Cluster's",
+                "field auth None",
+                "enter auth Auth",
+                "variant auth.Anonymous Anonymous",
+                "variant auth.Token Token",
+                "enter auth.Token Token",
+                "enter auth.Token Table of 'String => Integer'",
+                "leave auth.Token Token",
+                "leave auth Auth",
+                "leave  Config",
+            ]
+        );
+    }
+
+    struct Redact;
+
+    impl Fold for Redact {
+        fn fold_description(&mut self, mut desc: TypeDescription) -> TypeDescription {
+            desc.set_doc(None);
+            fold_description_children(self, desc)
+        }
+
+        fn fold_variant(&mut self, variant: EnumVariant) -> EnumVariant {
+            EnumVariant::new_owned(
+                variant.name().to_lowercase(),
+                None,
+                EnumVariantRepresentation::String(variant.name().to_lowercase().into()),
+            )
+        }
+    }
+
+    #[test]
+    fn fold_rewrites_tree() {
+        let desc = Redact.fold_description(config());
+
+        let TypeKind::Struct(fields) = desc.kind() else {
+            panic!("Expected Struct, got {:?}", desc.kind());
+        };
+        assert_eq!(fields[0].doc(), Some("The clusters to connect to"));
+        let TypeKind::Array(cluster) = fields[0].kind().kind() else {
+            panic!("Expected Array, got {:?}", fields[0].kind().kind());
+        };
+        assert_eq!(cluster.doc(), None);
+        let TypeKind::Enum(_, variants) = fields[1].kind().kind() else {
+            panic!("Expected Enum, got {:?}", fields[1].kind().kind());
+        };
+        assert_eq!(
+            variants.iter().map(|v| v.name()).collect::<Vec<_>>(),
+            ["anonymous", "token"]
+        );
+    }
+}
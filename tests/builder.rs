@@ -0,0 +1,73 @@
+//
+//   This Source Code Form is subject to the terms of the Mozilla Public
+//   License, v. 2.0. If a copy of the MPL was not distributed with this
+//   file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+#![allow(dead_code)]
+
+use std::collections::HashMap;
+
+use type_description::{
+    AsTypeDescription, EnumVariant, EnumVariantRepresentation, StructField, TypeDescription,
+    TypeEnumKind,
+};
+
+/// A cluster to connect to
+#[derive(TypeDescription)]
+struct Cluster {
+    /// The server's address
+    server: String,
+    ports: [u16; 2],
+    labels: HashMap<String, Vec<String>>,
+}
+
+#[derive(TypeDescription)]
+#[description(tag = "type")]
+enum Auth {
+    /// No authentication at all
+    Anonymous,
+    Token(Cluster),
+}
+
+#[test]
+fn build_struct() {
+    let built = TypeDescription::builder("Cluster")
+        .doc("A cluster to connect to")
+        .documented_field(
+            "server",
+            "The server's address",
+            String::as_type_description(),
+        )
+        .field(
+            "ports",
+            TypeDescription::fixed_array_of(u16::as_type_description(), 2),
+        )
+        .push_field(StructField::new(
+            "labels",
+            None,
+            TypeDescription::map_of(
+                String::as_type_description(),
+                TypeDescription::array_of(String::as_type_description()),
+            ),
+            false,
+        ))
+        .build();
+
+    assert_eq!(built, Cluster::as_type_description());
+}
+
+#[test]
+fn build_enum() {
+    let built = TypeDescription::enum_builder("Auth")
+        .tagging(TypeEnumKind::Tagged("type".into()))
+        .push_variant(EnumVariant::new(
+            "Anonymous",
+            Some("No authentication at all"),
+            EnumVariantRepresentation::String("Anonymous".into()),
+        ))
+        .variant("Token", Cluster::as_type_description())
+        .build();
+
+    assert_eq!(built, Auth::as_type_description());
+}
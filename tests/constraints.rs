@@ -0,0 +1,95 @@
+//
+//   This Source Code Form is subject to the terms of the Mozilla Public
+//   License, v. 2.0. If a copy of the MPL was not distributed with this
+//   file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+#![allow(dead_code)]
+
+use type_description::{
+    AsTypeDescription, Constraints, EnumVariantRepresentation, TypeDescription, TypeKind,
+};
+
+/// A TCP port
+#[derive(TypeDescription)]
+#[description(min = 1, max = 65535)]
+struct Port(u16);
+
+#[derive(TypeDescription)]
+struct Server {
+    #[description(min_len = 1, pattern = "^[a-z.]+$")]
+    host: String,
+    #[description(max = 1024)]
+    port: Port,
+    #[description(min = "-10", max = 10.5)]
+    offset: f32,
+}
+
+/// How often to retry a request
+#[derive(TypeDescription)]
+struct Retries(#[description(max = 10)] u8);
+
+#[derive(TypeDescription)]
+enum Limit {
+    Count(#[description(min = 1)] u32),
+    Unlimited,
+}
+
+#[test]
+fn newtype_constraints() {
+    let desc = Port::as_type_description();
+
+    assert_eq!(
+        desc.constraints(),
+        &Constraints::new().with_min(1).with_max(65535)
+    );
+}
+
+#[test]
+fn field_constraints() {
+    let desc = Server::as_type_description();
+    let TypeKind::Struct(fields) = desc.kind() else {
+        panic!("Expected a struct, got {:?}", desc.kind());
+    };
+
+    assert_eq!(
+        fields[0].kind().constraints(),
+        &Constraints::new().with_min_len(1).with_pattern("^[a-z.]+$")
+    );
+    // Field constraints refine the ones of the field's type
+    assert_eq!(
+        fields[1].kind().constraints(),
+        &Constraints::new().with_min(1).with_max(1024)
+    );
+    assert_eq!(
+        fields[2].kind().constraints().to_string(),
+        "min: -10, max: 10.5"
+    );
+}
+
+#[test]
+fn variant_constraints() {
+    let desc = Limit::as_type_description();
+    let TypeKind::Enum(_, variants) = desc.kind() else {
+        panic!("Expected an enum, got {:?}", desc.kind());
+    };
+    let EnumVariantRepresentation::Wrapped(count) = variants[0].repr() else {
+        panic!("Expected a wrapped variant, got {:?}", variants[0].repr());
+    };
+    let TypeKind::Wrapped(inner) = count.kind() else {
+        panic!("Expected a wrapped kind, got {:?}", count.kind());
+    };
+
+    assert_eq!(inner.constraints(), &Constraints::new().with_min(1));
+}
+
+#[test]
+fn newtype_field_constraints() {
+    let desc = Retries::as_type_description();
+    let TypeKind::Wrapped(inner) = desc.kind() else {
+        panic!("Expected a wrapped kind, got {:?}", desc.kind());
+    };
+
+    assert!(desc.constraints().is_empty());
+    assert_eq!(inner.constraints(), &Constraints::new().with_max(10));
+}
@@ -0,0 +1,138 @@
+//
+//   This Source Code Form is subject to the terms of the Mozilla Public
+//   License, v. 2.0. If a copy of the MPL was not distributed with this
+//   file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+#![allow(dead_code)]
+
+use serde::{Deserialize, Serialize};
+use type_description::{AsTypeDescription, StructField, TypeDescription, TypeKind};
+
+fn default_port() -> u16 {
+    8080
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct Opaque(u8);
+
+impl AsTypeDescription for Opaque {
+    fn as_type_description() -> TypeDescription {
+        u8::as_type_description()
+    }
+}
+
+#[derive(TypeDescription, Deserialize)]
+#[description(use_serde)]
+struct Server {
+    host: String,
+    #[serde(default = "default_port")]
+    port: u16,
+    #[serde(default)]
+    workers: Vec<String>,
+    #[serde(default)]
+    opaque: Opaque,
+    #[description(default = "info")]
+    log_level: String,
+}
+
+#[derive(TypeDescription, Serialize, Deserialize)]
+#[description(use_serde)]
+#[serde(default)]
+struct Limits {
+    connections: u32,
+    #[serde(default = "default_port")]
+    backlog: u16,
+}
+
+impl Default for Limits {
+    fn default() -> Self {
+        Self {
+            connections: 100,
+            backlog: 0,
+        }
+    }
+}
+
+#[derive(TypeDescription, Deserialize)]
+#[description(use_serde)]
+struct Wrapper<T> {
+    #[serde(default)]
+    inner: T,
+    #[serde(default)]
+    retries: u8,
+}
+
+fn fields(desc: &TypeDescription) -> &[StructField] {
+    match desc.kind() {
+        TypeKind::Struct(fields) => fields,
+        kind => panic!("Expected a struct, got {kind:?}"),
+    }
+}
+
+fn defaults(desc: &TypeDescription) -> Vec<Option<String>> {
+    fields(desc)
+        .iter()
+        .map(|field| field.default_value().map(ToString::to_string))
+        .collect()
+}
+
+#[test]
+fn field_defaults() {
+    let desc = Server::as_type_description();
+
+    assert_eq!(
+        defaults(&desc),
+        vec![
+            None,
+            Some(String::from("8080")),
+            Some(String::from("[]")),
+            None,
+            Some(String::from("\"info\"")),
+        ]
+    );
+    assert_eq!(
+        fields(&desc)
+            .iter()
+            .map(StructField::optional)
+            .collect::<Vec<_>>(),
+        vec![false, true, true, true, false]
+    );
+}
+
+#[test]
+fn documented_defaults_stay_required() {
+    let desc = Server::as_type_description();
+    let log_level = &fields(&desc)[4];
+
+    assert!(log_level.default_value().is_some());
+    assert!(!log_level.optional());
+    assert!(serde_json::from_value::<Server>(serde_json::json!({ "host": "localhost" })).is_err());
+}
+
+#[test]
+fn container_defaults() {
+    let desc = Limits::as_type_description();
+
+    assert_eq!(
+        defaults(&desc),
+        vec![Some(String::from("100")), Some(String::from("8080"))]
+    );
+    assert!(fields(&desc).iter().all(StructField::optional));
+}
+
+#[test]
+fn generic_defaults_are_skipped() {
+    let desc = Wrapper::<Opaque>::as_type_description();
+
+    assert_eq!(defaults(&desc), vec![None, Some(String::from("0"))]);
+}
+
+#[cfg(feature = "render_markdown")]
+#[test]
+fn render_defaults() {
+    let markdown =
+        type_description::render::render_to_markdown(&Server::as_type_description()).unwrap();
+
+    assert!(markdown.contains("- `port` (Integer, default: `8080`)"));
+}
@@ -0,0 +1,77 @@
+//
+//   This Source Code Form is subject to the terms of the Mozilla Public
+//   License, v. 2.0. If a copy of the MPL was not distributed with this
+//   file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+#![allow(dead_code)]
+
+use serde::{Deserialize, Serialize};
+use type_description::{AsTypeDescription, TypeDescription, TypeEnumKind, TypeKind};
+
+#[derive(Debug, TypeDescription)]
+enum Door {
+    Wooden,
+    Steel,
+    Plastic,
+}
+
+#[derive(Debug, TypeDescription)]
+#[description(tag = "t", content = "c")]
+enum Window {
+    Open { width: u8 },
+    Closed,
+}
+
+#[derive(Debug, TypeDescription, Serialize, Deserialize)]
+#[serde(tag = "kind", content = "value")]
+#[description(use_serde)]
+enum Light {
+    On(u8),
+    Off,
+}
+
+#[derive(Debug, TypeDescription, Serialize, Deserialize)]
+#[serde(untagged)]
+#[description(use_serde)]
+enum Heating {
+    Temperature(f32),
+    Preset(String),
+}
+
+fn enum_kind(desc: &TypeDescription) -> &TypeEnumKind {
+    match desc.kind() {
+        TypeKind::Enum(kind, _) => kind,
+        other => panic!("Expected Enum, got {:?}", other),
+    }
+}
+
+#[test]
+fn check_enum_tagging() {
+    assert_eq!(
+        enum_kind(&Door::as_type_description()),
+        &TypeEnumKind::External
+    );
+    assert_eq!(
+        enum_kind(&Window::as_type_description()),
+        &TypeEnumKind::Adjacent {
+            tag: "t".into(),
+            content: "c".into()
+        }
+    );
+}
+
+#[test]
+fn check_serde_enum_tagging() {
+    assert_eq!(
+        enum_kind(&Light::as_type_description()),
+        &TypeEnumKind::Adjacent {
+            tag: "kind".into(),
+            content: "value".into()
+        }
+    );
+    assert_eq!(
+        enum_kind(&Heating::as_type_description()),
+        &TypeEnumKind::Untagged
+    );
+}
@@ -0,0 +1,123 @@
+//
+//   This Source Code Form is subject to the terms of the Mozilla Public
+//   License, v. 2.0. If a copy of the MPL was not distributed with this
+//   file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+#![allow(dead_code)]
+
+use std::{fmt::Debug, marker::PhantomData};
+
+use type_description::{AsTypeDescription, TypeDescription, TypeKind};
+
+#[derive(Debug, TypeDescription)]
+struct Wrapper<T> {
+    inner: T,
+}
+
+#[derive(Debug, TypeDescription)]
+struct Pair<A, B>
+where
+    A: Debug,
+{
+    left: A,
+    right: Vec<B>,
+}
+
+#[derive(Debug, TypeDescription)]
+#[description(tag = "type")]
+enum Either<L, R> {
+    Left { value: L },
+    Right { value: R },
+}
+
+#[derive(Debug, TypeDescription)]
+#[description(bound = "T: Debug + AsTypeDescription")]
+struct Bounded<T> {
+    value: Option<T>,
+}
+
+/// A marker that is described the same way for every `T`
+#[derive(Debug)]
+struct Ghost<T>(PhantomData<T>);
+
+impl<T> AsTypeDescription for Ghost<T> {
+    fn as_type_description() -> TypeDescription {
+        TypeDescription::new_owned("Ghost", TypeKind::Unit, None)
+    }
+}
+
+/// A type that has no description
+#[derive(Debug)]
+struct Opaque;
+
+#[derive(Debug, TypeDescription)]
+#[description(bound = "")]
+struct Marker<T> {
+    id: u32,
+    marker: Ghost<T>,
+}
+
+#[derive(Debug, TypeDescription)]
+struct Tree<T> {
+    value: T,
+    children: Vec<Tree<T>>,
+}
+
+#[test]
+fn check_generic_names() {
+    assert_eq!(
+        Wrapper::<String>::as_type_description().name(),
+        "Wrapper<String>"
+    );
+    assert_eq!(
+        Pair::<bool, u8>::as_type_description().name(),
+        "Pair<Boolean, Integer>"
+    );
+    assert_eq!(
+        Either::<Wrapper<bool>, f32>::as_type_description().name(),
+        "Either<Wrapper<Boolean>, Float>"
+    );
+    assert_eq!(
+        Bounded::<u8>::as_type_description().name(),
+        "Bounded<Integer>"
+    );
+}
+
+#[test]
+fn check_phantom_parameters() {
+    let name = Marker::<Opaque>::as_type_description().name().to_owned();
+    assert!(name.starts_with("Marker<"), "{name}");
+    assert!(name.ends_with("Opaque>"), "{name}");
+}
+
+#[test]
+fn check_generic_fields() {
+    let desc = Wrapper::<u16>::as_type_description();
+
+    match desc.kind() {
+        TypeKind::Struct(fields) => {
+            assert_eq!(fields[0].name(), "inner");
+            assert_eq!(fields[0].kind(), &u16::as_type_description());
+        }
+        other => panic!("Expected Struct, got {:?}", other),
+    }
+}
+
+#[test]
+fn check_recursive_generic() {
+    let desc = Tree::<String>::as_type_description();
+
+    match desc.kind() {
+        TypeKind::Struct(fields) => match fields[1].kind().kind() {
+            TypeKind::Array(element) => {
+                assert_eq!(
+                    element.as_ref(),
+                    &TypeDescription::reference("Tree<String>")
+                )
+            }
+            other => panic!("Expected Array, got {:?}", other),
+        },
+        other => panic!("Expected Struct, got {:?}", other),
+    }
+}
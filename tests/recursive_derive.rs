@@ -0,0 +1,88 @@
+//
+//   This Source Code Form is subject to the terms of the Mozilla Public
+//   License, v. 2.0. If a copy of the MPL was not distributed with this
+//   file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+#![allow(dead_code)]
+
+use type_description::{AsTypeDescription, TypeDescription, TypeKind, TypeRegistry};
+
+/// A node in a tree
+#[derive(Debug, TypeDescription)]
+struct Node {
+    value: String,
+    children: Vec<Node>,
+    parent: Option<Box<Node>>,
+}
+
+#[derive(Debug, TypeDescription)]
+struct Forest {
+    trees: Vec<Node>,
+    biggest: Node,
+}
+
+/// A tree made of nothing but its branches
+#[derive(Debug, TypeDescription)]
+struct Tree(Vec<Tree>);
+
+#[derive(Debug, TypeDescription)]
+struct Holder {
+    tree: Tree,
+}
+
+#[test]
+fn check_recursive_type_description() {
+    let desc = Node::as_type_description();
+
+    match desc.kind() {
+        TypeKind::Struct(fields) => match fields[1].kind().kind() {
+            TypeKind::Array(element) => {
+                assert_eq!(element.kind(), &TypeKind::Reference(String::from("Node")))
+            }
+            other => panic!("Expected Array, got {:?}", other),
+        },
+        other => panic!("Expected Struct, got {:?}", other),
+    }
+}
+
+#[test]
+fn check_registry() {
+    let mut registry = TypeRegistry::new();
+    let forest = registry.register::<Forest>();
+
+    assert_eq!(forest, TypeDescription::reference("Forest"));
+    assert_eq!(registry.len(), 2);
+
+    let node = registry.get("Node").unwrap();
+    assert_eq!(node.doc(), Some("A node in a tree"));
+
+    match registry.get("Forest").unwrap().kind() {
+        TypeKind::Struct(fields) => {
+            assert_eq!(fields[1].kind(), &TypeDescription::reference("Node"));
+        }
+        other => panic!("Expected Struct, got {:?}", other),
+    }
+}
+
+#[test]
+fn check_recursive_newtype() {
+    let mut registry = TypeRegistry::new();
+    let holder = registry.register::<Holder>();
+
+    assert_eq!(holder, TypeDescription::reference("Holder"));
+    assert_eq!(
+        registry.iter().map(|desc| desc.name()).collect::<Vec<_>>(),
+        vec!["Holder", "Tree"]
+    );
+
+    match registry.get("Tree").unwrap().kind() {
+        TypeKind::Wrapped(inner) => match inner.kind() {
+            TypeKind::Array(element) => {
+                assert_eq!(element.as_ref(), &TypeDescription::reference("Tree"))
+            }
+            other => panic!("Expected Array, got {:?}", other),
+        },
+        other => panic!("Expected Wrapped, got {:?}", other),
+    }
+}
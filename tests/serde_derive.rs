@@ -7,7 +7,9 @@
 #![allow(dead_code)]
 
 use serde::{Deserialize, Serialize};
-use type_description::{AsTypeDescription, TypeDescription};
+use type_description::{
+    AsTypeDescription, BytesEncoding, EnumVariantRepresentation, TypeDescription, TypeKind,
+};
 
 #[derive(Debug, TypeDescription, Serialize, Deserialize)]
 struct Window {
@@ -75,3 +77,189 @@ fn check_type_description() {
 
     println!("{:#?}", desc);
 }
+
+#[derive(Debug, TypeDescription, Serialize, Deserialize)]
+#[description(use_serde)]
+#[serde(rename_all = "kebab-case")]
+struct Manifest {
+    api_version: String,
+    #[serde(rename = "KIND")]
+    kind: String,
+    r#type: String,
+}
+
+#[derive(Debug, TypeDescription, Serialize, Deserialize)]
+#[description(use_serde)]
+#[serde(
+    tag = "type",
+    rename_all = "SCREAMING_SNAKE_CASE",
+    rename_all_fields = "camelCase"
+)]
+enum Event {
+    PowerOn,
+    #[serde(rename = "off")]
+    PowerOff,
+    SetTemperature {
+        target_temp: f32,
+    },
+    #[serde(rename_all = "PascalCase")]
+    SetHumidity {
+        target_humidity: f32,
+        #[serde(rename(deserialize = "hysteresis"))]
+        humidity_hysteresis: f32,
+    },
+}
+
+fn struct_field_names(desc: &TypeDescription) -> Vec<&str> {
+    match desc.kind() {
+        TypeKind::Struct(fields) => fields.iter().map(|field| field.name()).collect(),
+        kind => panic!("Expected a struct, got {kind:?}"),
+    }
+}
+
+#[test]
+fn check_rename_all() {
+    let desc = Manifest::as_type_description();
+    assert_eq!(
+        struct_field_names(&desc),
+        vec!["api-version", "KIND", "type"]
+    );
+
+    let desc = Event::as_type_description();
+    let TypeKind::Enum(_, variants) = desc.kind() else {
+        panic!("Expected an enum, got {:?}", desc.kind());
+    };
+
+    let names = variants.iter().map(|var| var.name()).collect::<Vec<_>>();
+    assert_eq!(
+        names,
+        vec!["POWER_ON", "off", "SET_TEMPERATURE", "SET_HUMIDITY"]
+    );
+    assert!(matches!(
+        variants[0].repr(),
+        EnumVariantRepresentation::String(repr) if repr == "POWER_ON"
+    ));
+
+    let payload_names = variants[2..]
+        .iter()
+        .map(|var| match var.repr() {
+            EnumVariantRepresentation::Wrapped(payload) => struct_field_names(payload),
+            repr => panic!("Expected a payload, got {repr:?}"),
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        payload_names,
+        vec![vec!["targetTemp"], vec!["TargetHumidity", "hysteresis"]]
+    );
+}
+
+#[derive(Debug, TypeDescription, Serialize, Deserialize)]
+#[description(use_serde)]
+struct Attachment {
+    #[serde(with = "serde_bytes")]
+    content: Vec<u8>,
+    #[serde(with = "serde_bytes", default)]
+    thumbnail: Option<Vec<u8>>,
+    #[description(bytes = "base64")]
+    signature: String,
+    checksum: Vec<u8>,
+}
+
+#[test]
+fn check_bytes() {
+    let desc = Attachment::as_type_description();
+    let TypeKind::Struct(fields) = desc.kind() else {
+        panic!("Expected a struct, got {:?}", desc.kind());
+    };
+
+    let kinds = fields
+        .iter()
+        .map(|field| field.kind().kind())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        kinds[0],
+        &TypeKind::Bytes {
+            encoding: BytesEncoding::Raw
+        }
+    );
+    assert!(matches!(
+        kinds[1],
+        TypeKind::Optional(inner) if inner.kind() == &TypeKind::Bytes { encoding: BytesEncoding::Raw }
+    ));
+    assert!(fields[1].optional());
+    assert_eq!(
+        kinds[2],
+        &TypeKind::Bytes {
+            encoding: BytesEncoding::Base64
+        }
+    );
+    assert!(matches!(kinds[3], TypeKind::Array(_)));
+}
+
+#[derive(Debug, TypeDescription, Serialize, Deserialize)]
+#[description(use_serde)]
+enum Payload {
+    Raw(#[serde(with = "serde_bytes")] Vec<u8>),
+    Encoded(#[description(bytes = "hex")] String),
+}
+
+#[test]
+fn check_variant_bytes() {
+    let desc = Payload::as_type_description();
+    let TypeKind::Enum(_, variants) = desc.kind() else {
+        panic!("Expected an enum, got {:?}", desc.kind());
+    };
+
+    let kinds = variants
+        .iter()
+        .map(|variant| match variant.repr() {
+            EnumVariantRepresentation::Wrapped(desc) => match desc.kind() {
+                TypeKind::Wrapped(inner) => inner.kind().clone(),
+                kind => panic!("Expected a wrapped variant, got {kind:?}"),
+            },
+            repr => panic!("Expected a wrapped variant, got {repr:?}"),
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        kinds,
+        vec![
+            TypeKind::Bytes {
+                encoding: BytesEncoding::Raw
+            },
+            TypeKind::Bytes {
+                encoding: BytesEncoding::Hex
+            },
+        ]
+    );
+}
+
+#[derive(Debug, TypeDescription, Serialize, Deserialize)]
+#[description(use_serde)]
+#[serde(tag = "kind")]
+enum Shape {
+    Circle {
+        radius: f64,
+    },
+    #[serde(skip)]
+    Cached {
+        id: u64,
+    },
+    #[serde(skip_deserializing)]
+    Legacy,
+    #[serde(other)]
+    Unknown,
+}
+
+#[test]
+fn check_skipped_variants() {
+    let desc = Shape::as_type_description();
+    let TypeKind::Enum(_, variants) = desc.kind() else {
+        panic!("Expected an enum, got {:?}", desc.kind());
+    };
+
+    let names = variants
+        .iter()
+        .map(|variant| variant.name())
+        .collect::<Vec<_>>();
+    assert_eq!(names, vec!["Circle"]);
+}
@@ -0,0 +1,46 @@
+//
+//   This Source Code Form is subject to the terms of the Mozilla Public
+//   License, v. 2.0. If a copy of the MPL was not distributed with this
+//   file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+use type_description::{AsTypeDescription, StringFormat, TypeDescription, TypeKind};
+
+/// A description serialized by an earlier version of this crate
+const BASELINE: &str = include_str!("fixtures/baseline_description.json");
+
+#[test]
+fn baseline_descriptions_round_trip() {
+    let desc: TypeDescription = serde_json::from_str(BASELINE).unwrap();
+
+    let TypeKind::Struct(fields) = desc.kind() else {
+        panic!("Expected a struct, got {:?}", desc.kind());
+    };
+    assert_eq!(fields[0].kind().kind(), &TypeKind::String);
+
+    let expected: serde_json::Value = serde_json::from_str(BASELINE).unwrap();
+    assert_eq!(serde_json::to_value(&desc).unwrap(), expected);
+}
+
+#[test]
+fn plain_strings_keep_their_serialized_form() {
+    assert_eq!(
+        serde_json::to_value(String::as_type_description().kind()).unwrap(),
+        serde_json::json!("String")
+    );
+
+    let socket = std::net::SocketAddr::as_type_description();
+    assert_eq!(
+        socket.kind(),
+        &TypeKind::FormattedString(StringFormat::SocketAddr)
+    );
+    let kind = serde_json::to_value(socket.kind()).unwrap();
+    assert_eq!(
+        kind,
+        serde_json::json!({ "FormattedString": "socket-addr" })
+    );
+    assert_eq!(
+        serde_json::from_value::<TypeKind>(kind).unwrap(),
+        TypeKind::FormattedString(StringFormat::SocketAddr)
+    );
+}
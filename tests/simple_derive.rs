@@ -6,7 +6,7 @@
 
 #![allow(dead_code)]
 
-use type_description::{AsTypeDescription, TypeDescription};
+use type_description::{AsTypeDescription, TypeDescription, TypeKind};
 
 #[derive(Debug, TypeDescription)]
 
@@ -42,3 +42,29 @@ fn check_type_description() {
 
     println!("{:#?}", desc);
 }
+
+type Maybe<T> = Option<T>;
+
+#[derive(Debug, TypeDescription)]
+struct Profile {
+    name: String,
+    nickname: Option<String>,
+    aliases: std::option::Option<Vec<String>>,
+    middle_name: Maybe<String>,
+}
+
+#[test]
+fn option_fields_are_optional() {
+    let desc = Profile::as_type_description();
+
+    let TypeKind::Struct(fields) = desc.kind() else {
+        panic!("Expected a struct, got {:?}", desc.kind());
+    };
+    assert_eq!(
+        fields
+            .iter()
+            .map(|field| field.optional())
+            .collect::<Vec<_>>(),
+        [false, true, true, true]
+    );
+}
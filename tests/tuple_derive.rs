@@ -0,0 +1,79 @@
+//
+//   This Source Code Form is subject to the terms of the Mozilla Public
+//   License, v. 2.0. If a copy of the MPL was not distributed with this
+//   file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+#![allow(dead_code)]
+
+use type_description::{AsTypeDescription, EnumVariantRepresentation, TypeDescription, TypeKind};
+
+/// A range of ports
+#[derive(Debug, TypeDescription)]
+struct PortRange(u16, u16);
+
+#[derive(Debug, TypeDescription)]
+#[description(untagged)]
+enum Position {
+    Point(f32, f32, f32),
+    Vector([f32; 3]),
+}
+
+#[derive(Debug, TypeDescription)]
+struct Listener {
+    ports: PortRange,
+    forward: (String, u16),
+    position: Position,
+}
+
+#[test]
+fn check_tuple_struct() {
+    let desc = PortRange::as_type_description();
+
+    assert_eq!(desc.doc(), Some("A range of ports"));
+    match desc.kind() {
+        TypeKind::Tuple(elements) => {
+            assert_eq!(elements.len(), 2);
+            assert_eq!(elements[0], u16::as_type_description());
+        }
+        other => panic!("Expected Tuple, got {:?}", other),
+    }
+}
+
+#[test]
+fn check_tuple_variant() {
+    let desc = Position::as_type_description();
+
+    match desc.kind() {
+        TypeKind::Enum(_, variants) => {
+            match variants[0].repr() {
+                EnumVariantRepresentation::Wrapped(point) => {
+                    assert!(
+                        matches!(point.kind(), TypeKind::Tuple(elements) if elements.len() == 3)
+                    )
+                }
+                other => panic!("Expected Wrapped, got {:?}", other),
+            }
+            match variants[1].repr() {
+                EnumVariantRepresentation::Wrapped(vector) => assert!(matches!(
+                    vector.kind(),
+                    TypeKind::Wrapped(inner) if matches!(inner.kind(), TypeKind::FixedArray { length: 3, .. })
+                )),
+                other => panic!("Expected Wrapped, got {:?}", other),
+            }
+        }
+        other => panic!("Expected Enum, got {:?}", other),
+    }
+}
+
+#[test]
+fn check_tuple_fields() {
+    let desc = Listener::as_type_description();
+
+    match desc.kind() {
+        TypeKind::Struct(fields) => {
+            assert_eq!(fields[1].kind().name(), "Tuple of (String, Integer)");
+        }
+        other => panic!("Expected Struct, got {:?}", other),
+    }
+}
@@ -9,6 +9,7 @@
 use type_description::TypeDescription;
 
 #[derive(Debug, TypeDescription)]
+#[description(content = "c")]
 enum Door {
     Wooden,
     Steel,
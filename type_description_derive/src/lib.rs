@@ -5,21 +5,25 @@
 //
 
 use proc_macro::TokenStream as TS;
-use proc_macro2::TokenStream;
-use proc_macro_error::{abort, proc_macro_error, OptionExt, ResultExt};
+use proc_macro2::{TokenStream, TokenTree};
+use proc_macro_error::{abort, proc_macro_error, ResultExt};
 use quote::{quote, ToTokens, TokenStreamExt};
 use syn::{
-    parse_macro_input, Attribute, DeriveInput, Ident, Lit, LitStr, Meta, MetaNameValue, NestedMeta,
-    Type,
+    ext::IdentExt, parse::Parser, parse_macro_input, parse_quote, punctuated::Punctuated,
+    Attribute, DeriveInput, ExprPath, Field, FieldsNamed, Ident, Lit, LitStr, Meta, MetaNameValue,
+    NestedMeta, Token, Type, TypeParamBound, WherePredicate,
 };
 
 #[derive(Debug)]
 enum TypeField<'q> {
     Simple {
-        ident: Ident,
+        name: String,
         ty: &'q Type,
         docs: Option<Vec<LitStr>>,
         optional: bool,
+        default: Option<TokenStream>,
+        constraints: ConstraintAttributes,
+        bytes: Option<TokenStream>,
     },
     Flatten {
         ty: &'q Type,
@@ -30,31 +34,35 @@ enum TypeField<'q> {
 enum TypeVariantKind<'q> {
     String(&'q Ident),
     Wrapped(&'q Ident, TypeField<'q>),
+    Tuple(&'q Ident, Vec<&'q Type>),
     Struct(&'q Ident, Vec<TypeField<'q>>),
 }
 
 #[derive(Debug)]
 struct TypeVariant<'q> {
+    name: String,
     kind: TypeVariantKind<'q>,
     docs: Option<Vec<LitStr>>,
 }
 
 #[derive(Debug)]
 enum TypeEnumKind {
+    External,
     Tagged(LitStr),
+    Adjacent { tag: LitStr, content: LitStr },
     Untagged,
 }
 
 #[derive(Debug)]
 enum TypeQuoteKind<'q> {
-    Wrapped(&'q Type),
+    Wrapped(TypeField<'q>),
+    Tuple(Vec<&'q Type>),
     Struct(Vec<TypeField<'q>>),
     Enum(TypeEnumKind, Vec<TypeVariant<'q>>),
 }
 
 #[derive(Debug)]
 struct TypeQuote<'q> {
-    ident: &'q Ident,
     docs: Option<Vec<LitStr>>,
     kind: TypeQuoteKind<'q>,
 }
@@ -95,12 +103,126 @@ fn extract_docs_from_attributes<'a>(
     }
 }
 
+/// The casing rules accepted by serde's `rename_all` attributes
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum RenameRule {
+    Lower,
+    Upper,
+    Pascal,
+    Camel,
+    Snake,
+    ScreamingSnake,
+    Kebab,
+    ScreamingKebab,
+}
+
+impl RenameRule {
+    fn from_lit(lit: &LitStr) -> Self {
+        match lit.value().as_str() {
+            "lowercase" => RenameRule::Lower,
+            "UPPERCASE" => RenameRule::Upper,
+            "PascalCase" => RenameRule::Pascal,
+            "camelCase" => RenameRule::Camel,
+            "snake_case" => RenameRule::Snake,
+            "SCREAMING_SNAKE_CASE" => RenameRule::ScreamingSnake,
+            "kebab-case" => RenameRule::Kebab,
+            "SCREAMING-KEBAB-CASE" => RenameRule::ScreamingKebab,
+            _ => abort!(
+                lit,
+                "Unknown rename rule";
+                help = "Use one of \"lowercase\", \"UPPERCASE\", \"PascalCase\", \"camelCase\", \"snake_case\", \"SCREAMING_SNAKE_CASE\", \"kebab-case\" or \"SCREAMING-KEBAB-CASE\"."
+            ),
+        }
+    }
+
+    /// Rename a field, which is expected to be written in snake_case
+    fn apply_to_field(self, field: &str) -> String {
+        match self {
+            RenameRule::Lower | RenameRule::Snake => field.to_owned(),
+            RenameRule::Upper | RenameRule::ScreamingSnake => field.to_ascii_uppercase(),
+            RenameRule::Pascal => {
+                let mut pascal = String::new();
+                let mut capitalize = true;
+                for ch in field.chars() {
+                    if ch == '_' {
+                        capitalize = true;
+                    } else if capitalize {
+                        pascal.push(ch.to_ascii_uppercase());
+                        capitalize = false;
+                    } else {
+                        pascal.push(ch);
+                    }
+                }
+                pascal
+            }
+            RenameRule::Camel => {
+                let pascal = RenameRule::Pascal.apply_to_field(field);
+                pascal[..1].to_ascii_lowercase() + &pascal[1..]
+            }
+            RenameRule::Kebab => field.replace('_', "-"),
+            RenameRule::ScreamingKebab => field.to_ascii_uppercase().replace('_', "-"),
+        }
+    }
+
+    /// Rename a variant, which is expected to be written in PascalCase
+    fn apply_to_variant(self, variant: &str) -> String {
+        match self {
+            RenameRule::Pascal => variant.to_owned(),
+            RenameRule::Lower => variant.to_ascii_lowercase(),
+            RenameRule::Upper => variant.to_ascii_uppercase(),
+            RenameRule::Camel => variant[..1].to_ascii_lowercase() + &variant[1..],
+            RenameRule::Snake => {
+                let mut snake = String::new();
+                for (i, ch) in variant.char_indices() {
+                    if i > 0 && ch.is_uppercase() {
+                        snake.push('_');
+                    }
+                    snake.push(ch.to_ascii_lowercase());
+                }
+                snake
+            }
+            RenameRule::ScreamingSnake => RenameRule::Snake
+                .apply_to_variant(variant)
+                .to_ascii_uppercase(),
+            RenameRule::Kebab => RenameRule::Snake
+                .apply_to_variant(variant)
+                .replace('_', "-"),
+            RenameRule::ScreamingKebab => RenameRule::ScreamingSnake
+                .apply_to_variant(variant)
+                .replace('_', "-"),
+        }
+    }
+}
+
+/// Get the name used for deserializing from either `rename = "..."` or
+/// `rename(deserialize = "...")`
+fn serde_rename_value(meta: &Meta) -> Option<LitStr> {
+    match meta {
+        Meta::NameValue(MetaNameValue {
+            lit: Lit::Str(litstr),
+            ..
+        }) => Some(litstr.clone()),
+        Meta::List(list) => list.nested.iter().find_map(|nested| match nested {
+            NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                path,
+                lit: Lit::Str(litstr),
+                ..
+            })) if path.is_ident("deserialize") => Some(litstr.clone()),
+            _ => None,
+        }),
+        _ => None,
+    }
+}
+
 #[derive(Debug, PartialEq)]
 enum SerdeFieldAttribute {
     Rename(LitStr),
+    RenameAll(RenameRule),
     HasDefault,
+    DefaultWith(ExprPath),
     Flatten,
     Skip,
+    Bytes,
 }
 
 fn extra_serde_field_attributes<'a>(
@@ -115,26 +237,43 @@ fn extra_serde_field_attributes<'a>(
                 .filter_map(|meta| match meta {
                     NestedMeta::Lit(_) => None,
                     NestedMeta::Meta(meta) => {
-                        match meta {
-                            Meta::NameValue(meta) => {
-                                if meta.path.is_ident("rename") {
-                                    if let Lit::Str(litstr) = meta.lit {
-                                        return Some(SerdeFieldAttribute::Rename(litstr));
-                                    }
-                                }
+                        if meta.path().is_ident("rename") {
+                            return serde_rename_value(&meta).map(SerdeFieldAttribute::Rename);
+                        }
+                        if meta.path().is_ident("rename_all") {
+                            return serde_rename_value(&meta).map(|litstr| {
+                                SerdeFieldAttribute::RenameAll(RenameRule::from_lit(&litstr))
+                            });
+                        }
+                        if let Meta::NameValue(MetaNameValue {
+                            path,
+                            lit: Lit::Str(litstr),
+                            ..
+                        }) = &meta
+                        {
+                            if path.is_ident("default") {
+                                return Some(SerdeFieldAttribute::DefaultWith(parse_default_path(
+                                    litstr,
+                                )));
+                            }
+                            if path.is_ident("with") && litstr.value() == "serde_bytes" {
+                                return Some(SerdeFieldAttribute::Bytes);
+                            }
+                        }
+                        if let Meta::Path(path) = meta {
+                            if path.is_ident("default") {
+                                return Some(SerdeFieldAttribute::HasDefault);
+                            }
+                            if path.is_ident("flatten") {
+                                return Some(SerdeFieldAttribute::Flatten);
                             }
-                            Meta::Path(path) => {
-                                if path.is_ident("default") {
-                                    return Some(SerdeFieldAttribute::HasDefault);
-                                }
-                                if path.is_ident("flatten") {
-                                    return Some(SerdeFieldAttribute::Flatten);
-                                }
-                                if path.is_ident("skip") || path.is_ident("skip_deserializing") {
-                                    return Some(SerdeFieldAttribute::Skip);
-                                }
+                            // The catch-all `other` variant is never written by users
+                            if path.is_ident("skip")
+                                || path.is_ident("skip_deserializing")
+                                || path.is_ident("other")
+                            {
+                                return Some(SerdeFieldAttribute::Skip);
                             }
-                            _ => {}
                         }
                         None
                     }
@@ -151,71 +290,623 @@ fn extra_serde_field_attributes<'a>(
     }
 }
 
+fn parse_default_path(litstr: &LitStr) -> ExprPath {
+    litstr
+        .parse()
+        .expect_or_abort("Expected the path of a function returning the default value")
+}
+
+/// Whether the given bound requires the type parameter to implement `AsTypeDescription`
+fn bound_describes(bound: &Punctuated<WherePredicate, Token![,]>, param: &Ident) -> bool {
+    bound.iter().any(|predicate| match predicate {
+        WherePredicate::Type(predicate) => {
+            matches!(&predicate.bounded_ty, Type::Path(ty) if ty.qself.is_none() && ty.path.is_ident(param))
+                && predicate.bounds.iter().any(|bound| match bound {
+                    TypeParamBound::Trait(bound) => bound
+                        .path
+                        .segments
+                        .last()
+                        .map_or(false, |segment| segment.ident == "AsTypeDescription"),
+                    TypeParamBound::Lifetime(_) => false,
+                })
+        }
+        _ => false,
+    })
+}
+
+/// Whether the given type mentions any of the given type parameters
+fn mentions_type_params(ty: &Type, type_params: &[&Ident]) -> bool {
+    fn visit(tokens: TokenStream, type_params: &[&Ident]) -> bool {
+        tokens.into_iter().any(|token| match token {
+            TokenTree::Ident(ident) => type_params.iter().any(|param| **param == ident),
+            TokenTree::Group(group) => visit(group.stream(), type_params),
+            _ => false,
+        })
+    }
+
+    visit(ty.to_token_stream(), type_params)
+}
+
+fn description_metas<'a>(attrs: impl Iterator<Item = &'a Attribute>) -> Vec<NestedMeta> {
+    attrs
+        .filter(|attr| attr.path.is_ident("description"))
+        .flat_map(|attr| {
+            match attr
+                .parse_meta()
+                .expect_or_abort("Could not parse #[description] meta attribute.")
+            {
+                Meta::List(list) => list.nested.into_iter().collect::<Vec<_>>(),
+                other => abort!(
+                    other,
+                    "Expected a list of attributes, like #[description(...)]"
+                ),
+            }
+        })
+        .collect()
+}
+
+fn option_quoted(value: Option<&TokenStream>) -> TokenStream {
+    match value {
+        Some(value) => quote!(Some(#value)),
+        None => quote!(None),
+    }
+}
+
+fn number_quoted(lit: &Lit) -> TokenStream {
+    let number = match lit {
+        Lit::Int(int) => int.base10_digits().to_string(),
+        Lit::Float(float) => float.base10_digits().to_string(),
+        // Negative numbers are not literals, so they have to be given as strings
+        Lit::Str(litstr) => litstr.value(),
+        _ => abort!(lit, "Expected a number"),
+    };
+
+    if let Ok(number) = number.parse::<i64>() {
+        quote!(::type_description::__private::Bound::Signed(#number))
+    } else if let Ok(number) = number.parse::<u64>() {
+        quote!(::type_description::__private::Bound::Unsigned(#number))
+    } else if let Some(number) = number
+        .parse::<f64>()
+        .ok()
+        .filter(|number| number.is_finite())
+    {
+        quote!(::type_description::__private::Bound::Float(#number))
+    } else {
+        abort!(lit, "Expected a number")
+    }
+}
+
+/// The `min`, `max`, `min_len`, `max_len` and `pattern` attributes of a field or container
+#[derive(Debug, Default)]
+struct ConstraintAttributes {
+    min: Option<TokenStream>,
+    max: Option<TokenStream>,
+    min_len: Option<usize>,
+    max_len: Option<usize>,
+    pattern: Option<LitStr>,
+}
+
+impl ConstraintAttributes {
+    fn is_constraint(path: &syn::Path) -> bool {
+        ["min", "max", "min_len", "max_len", "pattern"]
+            .iter()
+            .any(|name| path.is_ident(name))
+    }
+
+    fn parse(&mut self, path: &syn::Path, lit: &Lit) {
+        let length = || match lit {
+            Lit::Int(int) => int
+                .base10_parse::<usize>()
+                .expect_or_abort("Expected a length"),
+            _ => abort!(lit, "Expected a length"),
+        };
+
+        if path.is_ident("min") {
+            self.min = Some(number_quoted(lit));
+        } else if path.is_ident("max") {
+            self.max = Some(number_quoted(lit));
+        } else if path.is_ident("min_len") {
+            self.min_len = Some(length());
+        } else if path.is_ident("max_len") {
+            self.max_len = Some(length());
+        } else if let Lit::Str(pattern) = lit {
+            self.pattern = Some(pattern.clone());
+        } else {
+            abort!(lit, "Expected a regular expression string")
+        }
+    }
+
+    /// Wrap the expression `desc` so that it has these constraints added
+    fn apply(&self, desc: TokenStream) -> TokenStream {
+        let ConstraintAttributes {
+            min,
+            max,
+            min_len,
+            max_len,
+            pattern,
+        } = self;
+
+        let setters = [
+            min_len.map(|min_len| quote!(.with_min_len(#min_len))),
+            max_len.map(|max_len| quote!(.with_max_len(#max_len))),
+            pattern
+                .as_ref()
+                .map(|pattern| quote!(.with_pattern(#pattern))),
+        ]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>();
+
+        if setters.is_empty() && min.is_none() && max.is_none() {
+            return desc;
+        }
+
+        let bounds = (min.is_some() || max.is_some()).then(|| {
+            let min = option_quoted(min.as_ref());
+            let max = option_quoted(max.as_ref());
+            quote! {
+                let constraints = ::type_description::__private::with_bounds(constraints, #min, #max);
+            }
+        });
+
+        quote! {
+            {
+                let desc = #desc;
+                let constraints = desc.constraints().clone()#(#setters)*;
+                #bounds
+                desc.with_constraints(constraints)
+            }
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct FieldAttributes {
+    default: Option<Lit>,
+    constraints: ConstraintAttributes,
+    bytes: Option<TokenStream>,
+}
+
+fn bytes_encoding_quoted(lit: &Lit) -> TokenStream {
+    match lit {
+        Lit::Str(litstr) => match litstr.value().as_str() {
+            "raw" => quote!(::type_description::BytesEncoding::Raw),
+            "base64" => quote!(::type_description::BytesEncoding::Base64),
+            "hex" => quote!(::type_description::BytesEncoding::Hex),
+            _ => abort!(
+                lit,
+                "Unknown bytes encoding";
+                help = "Use one of \"raw\", \"base64\" or \"hex\"."
+            ),
+        },
+        _ => abort!(lit, "Expected a bytes encoding string"),
+    }
+}
+
+fn parse_field_attributes<'a>(attrs: impl Iterator<Item = &'a Attribute>) -> FieldAttributes {
+    let mut field = FieldAttributes::default();
+
+    for meta in description_metas(attrs) {
+        match meta {
+            NestedMeta::Meta(Meta::NameValue(MetaNameValue { path, lit, .. }))
+                if path.is_ident("default") =>
+            {
+                field.default = Some(lit);
+            }
+            NestedMeta::Meta(Meta::NameValue(MetaNameValue { path, lit, .. }))
+                if path.is_ident("bytes") =>
+            {
+                field.bytes = Some(bytes_encoding_quoted(&lit));
+            }
+            NestedMeta::Meta(Meta::NameValue(MetaNameValue { path, lit, .. }))
+                if ConstraintAttributes::is_constraint(&path) =>
+            {
+                field.constraints.parse(&path, &lit);
+            }
+            _ => abort!(meta, "Unknown description attribute"),
+        }
+    }
+
+    field
+}
+
+/// How the fields of a struct or struct variant are described
+struct FieldOptions<'a> {
+    use_serde: bool,
+    rename_rule: Option<RenameRule>,
+    /// An expression evaluating to the default value of the whole container, if it has one
+    ///
+    /// This is only evaluated for non-generic containers.
+    container_default: Option<TokenStream>,
+    type_params: &'a [&'a Ident],
+}
+
+fn named_fields<'q>(fields: &'q FieldsNamed, options: &FieldOptions) -> Vec<TypeField<'q>> {
+    fields
+        .named
+        .iter()
+        .filter_map(|field| {
+            let ident = field.ident.as_ref().unwrap();
+            let field_attrs = parse_field_attributes(field.attrs.iter());
+            let mut type_field = TypeField::Simple {
+                name: ident.unraw().to_string(),
+                ty: &field.ty,
+                docs: extract_docs_from_attributes(field.attrs.iter()),
+                // A documented default does not make serde accept the field missing
+                optional: false,
+                default: field_attrs.default.as_ref().map(|lit| quote!(#lit)),
+                constraints: field_attrs.constraints,
+                bytes: field_attrs.bytes,
+            };
+
+            if !options.use_serde {
+                return Some(type_field);
+            }
+
+            let serde_field_attrs =
+                extra_serde_field_attributes(field.attrs.iter()).unwrap_or_default();
+
+            if serde_field_attrs.contains(&SerdeFieldAttribute::Flatten) {
+                type_field = TypeField::Flatten { ty: &field.ty };
+            }
+
+            if let TypeField::Simple {
+                name,
+                ty,
+                optional,
+                default,
+                bytes,
+                ..
+            } = &mut type_field
+            {
+                if let Some(rule) = options.rename_rule {
+                    *name = rule.apply_to_field(name);
+                }
+
+                // Default values of generic fields cannot be computed without further bounds
+                let is_generic = mentions_type_params(ty, options.type_params);
+
+                if let Some(container_default) = &options.container_default {
+                    *optional = true;
+                    if default.is_none() && options.type_params.is_empty() {
+                        *default = Some(quote!(#container_default.#ident));
+                    }
+                }
+
+                for attr in serde_field_attrs {
+                    match attr {
+                        SerdeFieldAttribute::Rename(litstr) => {
+                            *name = litstr.value();
+                        }
+                        SerdeFieldAttribute::HasDefault => {
+                            *optional = true;
+                            if field_attrs.default.is_none() && !is_generic {
+                                *default =
+                                    Some(quote!(<#ty as ::std::default::Default>::default()));
+                            }
+                        }
+                        SerdeFieldAttribute::DefaultWith(path) => {
+                            *optional = true;
+                            if field_attrs.default.is_none() && !is_generic {
+                                *default = Some(quote!(#path()));
+                            }
+                        }
+                        SerdeFieldAttribute::Skip => {
+                            return None;
+                        }
+                        SerdeFieldAttribute::Bytes => {
+                            if bytes.is_none() {
+                                *bytes = Some(quote!(::type_description::BytesEncoding::Raw));
+                            }
+                        }
+                        _ => (),
+                    }
+                }
+            }
+
+            Some(type_field)
+        })
+        .collect()
+}
+
+/// Describe the single field of a newtype struct or newtype variant
+fn wrapped_field(
+    field: &Field,
+    name: String,
+    docs: Option<Vec<LitStr>>,
+    use_serde: bool,
+) -> TypeField<'_> {
+    let field_attrs = parse_field_attributes(field.attrs.iter());
+    if let Some(default) = &field_attrs.default {
+        abort!(
+            default,
+            "Wrapped fields cannot have a default value";
+            help = "Document the default on the fields using this type instead."
+        );
+    }
+
+    let serde_bytes = use_serde
+        && extra_serde_field_attributes(field.attrs.iter())
+            .unwrap_or_default()
+            .contains(&SerdeFieldAttribute::Bytes);
+
+    TypeField::Simple {
+        name,
+        ty: &field.ty,
+        docs,
+        optional: false,
+        default: None,
+        constraints: field_attrs.constraints,
+        bytes: field_attrs
+            .bytes
+            .or_else(|| serde_bytes.then(|| quote!(::type_description::BytesEncoding::Raw))),
+    }
+}
+
+/// Describe a field of the given type, with its encoding and constraints applied
+fn field_description_quoted(
+    ty: &Type,
+    bytes: &Option<TokenStream>,
+    constraints: &ConstraintAttributes,
+) -> TokenStream {
+    let desc = quote!(<#ty as ::type_description::AsTypeDescription>::as_type_description());
+    let desc = match bytes {
+        Some(encoding) => quote!(::type_description::__private::bytes(#desc, #encoding)),
+        None => desc,
+    };
+    constraints.apply(desc)
+}
+
+fn struct_fields_quoted(fields: &[TypeField]) -> TokenStream {
+    let fields = fields.iter().map(|field| match field {
+        TypeField::Simple {
+            name,
+            ty,
+            docs,
+            optional,
+            default,
+            constraints,
+            bytes,
+        } => {
+            let docs = lit_strings_to_string_quoted(docs);
+            let desc = field_description_quoted(ty, bytes, constraints);
+            let default = default.as_ref().map(|default| {
+                quote! {
+                    let field = {
+                        use ::type_description::__private::{NoDefault as _, SerializeDefault as _};
+                        let value = #default;
+                        (&::type_description::__private::DefaultWrap(&value)).__with_default_value(field)
+                    };
+                }
+            });
+            // Like serde, accept optional types to be missing
+            quote! {
+                [{
+                    let desc = #desc;
+                    let optional = #optional
+                        || ::core::matches!(desc.kind(), ::type_description::TypeKind::Optional(_));
+                    let field = ::type_description::StructField::new(#name, #docs, desc, optional);
+                    #default
+                    field
+                }]
+            }
+        }
+        TypeField::Flatten { ty } => {
+            quote! {
+                {
+                    let desc = <#ty as ::type_description::AsTypeDescription>::as_type_description();
+                    match desc.kind() {
+                        ::type_description::TypeKind::Struct(fields) => fields.clone(),
+                        _ => panic!("Tried to flatten a non-struct field")
+                    }
+                }
+            }
+        }
+    });
+
+    quote! {
+        ::type_description::TypeKind::Struct({
+            let mut fields = vec![];
+            #(
+                fields.extend(#fields);
+            )*
+            fields
+        })
+    }
+}
+
+fn tuple_kind_quoted(types: &[&Type]) -> TokenStream {
+    quote! {
+        ::type_description::TypeKind::Tuple(vec![
+            #(<#types as ::type_description::AsTypeDescription>::as_type_description()),*
+        ])
+    }
+}
+
+#[derive(Debug, Default)]
+struct ContainerAttributes {
+    use_serde: bool,
+    bound: Option<Punctuated<WherePredicate, Token![,]>>,
+    tag: Option<LitStr>,
+    content: Option<LitStr>,
+    untagged: bool,
+    constraints: ConstraintAttributes,
+}
+
+fn parse_container_attributes<'a>(
+    attrs: impl Iterator<Item = &'a Attribute>,
+) -> ContainerAttributes {
+    let mut container = ContainerAttributes::default();
+
+    for meta in description_metas(attrs) {
+        match &meta {
+            NestedMeta::Meta(Meta::Path(path)) if path.is_ident("use_serde") => {
+                container.use_serde = true;
+            }
+            NestedMeta::Meta(Meta::Path(path)) if path.is_ident("untagged") => {
+                container.untagged = true;
+            }
+            NestedMeta::Meta(Meta::NameValue(MetaNameValue { path, lit, .. }))
+                if ConstraintAttributes::is_constraint(path) =>
+            {
+                container.constraints.parse(path, lit);
+            }
+            NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                path,
+                lit: Lit::Str(lit_str),
+                ..
+            })) => {
+                if path.is_ident("tag") {
+                    container.tag = Some(lit_str.clone());
+                } else if path.is_ident("content") {
+                    container.content = Some(lit_str.clone());
+                } else if path.is_ident("bound") {
+                    container.bound = Some(
+                        Punctuated::<WherePredicate, Token![,]>::parse_terminated
+                            .parse_str(&lit_str.value())
+                            .map_err(|err| syn::Error::new(lit_str.span(), err))
+                            .expect_or_abort(
+                                "Could not parse #[description(bound = \"...\")] attribute.",
+                            ),
+                    );
+                } else {
+                    abort!(path, "Unknown description attribute")
+                }
+            }
+            _ => abort!(meta, "Unknown description attribute"),
+        }
+    }
+
+    container
+}
+
+#[derive(Debug, PartialEq)]
+enum SerdeContainerAttribute {
+    Tag(LitStr),
+    Content(LitStr),
+    Untagged,
+    RenameAll(RenameRule),
+    RenameAllFields(RenameRule),
+    HasDefault,
+    DefaultWith(ExprPath),
+}
+
+fn extra_serde_container_attributes<'a>(
+    attrs: impl Iterator<Item = &'a Attribute>,
+) -> Vec<SerdeContainerAttribute> {
+    attrs
+        .filter(|attr| attr.path.is_ident("serde"))
+        .flat_map(|attr| match attr.parse_meta() {
+            Ok(Meta::List(list)) => list
+                .nested
+                .into_iter()
+                .filter_map(|meta| match meta {
+                    NestedMeta::Meta(meta) if meta.path().is_ident("rename_all") => {
+                        serde_rename_value(&meta).map(|litstr| {
+                            SerdeContainerAttribute::RenameAll(RenameRule::from_lit(&litstr))
+                        })
+                    }
+                    NestedMeta::Meta(meta) if meta.path().is_ident("rename_all_fields") => {
+                        serde_rename_value(&meta).map(|litstr| {
+                            SerdeContainerAttribute::RenameAllFields(RenameRule::from_lit(&litstr))
+                        })
+                    }
+                    NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                        path,
+                        lit: Lit::Str(litstr),
+                        ..
+                    })) => {
+                        if path.is_ident("tag") {
+                            Some(SerdeContainerAttribute::Tag(litstr))
+                        } else if path.is_ident("default") {
+                            Some(SerdeContainerAttribute::DefaultWith(parse_default_path(
+                                &litstr,
+                            )))
+                        } else if path.is_ident("content") {
+                            Some(SerdeContainerAttribute::Content(litstr))
+                        } else {
+                            None
+                        }
+                    }
+                    NestedMeta::Meta(Meta::Path(path)) if path.is_ident("untagged") => {
+                        Some(SerdeContainerAttribute::Untagged)
+                    }
+                    NestedMeta::Meta(Meta::Path(path)) if path.is_ident("default") => {
+                        Some(SerdeContainerAttribute::HasDefault)
+                    }
+                    _ => None,
+                })
+                .collect::<Vec<_>>(),
+            _ => vec![],
+        })
+        .collect()
+}
+
 impl<'q> ToTokens for TypeQuote<'q> {
     fn to_tokens(&self, tokens: &mut TokenStream) {
-        let ident_name = self.ident.to_string();
         let outer_docs = lit_strings_to_string_quoted(&self.docs);
 
         tokens.append_all(match &self.kind {
-            TypeQuoteKind::Wrapped(ty) => {
+            TypeQuoteKind::Wrapped(TypeField::Flatten { ty: _ }) => {
+                unreachable!("Newtypes are never flattened")
+            }
+            TypeQuoteKind::Wrapped(TypeField::Simple {
+                ty,
+                constraints,
+                bytes,
+                ..
+            }) => {
+                let desc = field_description_quoted(ty, bytes, constraints);
                 quote! {
                     ::type_description::TypeDescription::new(
-                        ::std::string::String::from(#ident_name),
+                        type_name.clone(),
                         ::type_description::TypeKind::Wrapped(
-                            ::std::boxed::Box::new(<#ty as ::type_description::AsTypeDescription>::as_type_description())
+                            ::std::boxed::Box::new(#desc)
                         ),
                         #outer_docs
                     )
                 }
             }
+            TypeQuoteKind::Tuple(types) => {
+                let kind = tuple_kind_quoted(types);
+                quote! {
+                    ::type_description::TypeDescription::new(
+                        type_name.clone(),
+                        #kind,
+                        #outer_docs
+                    )
+                }
+            }
             TypeQuoteKind::Struct(fields) => {
-
-                let fields = fields.iter().map(|field| {
-
-                    match field {
-                        TypeField::Simple { ident, ty, docs, optional } =>  {
-                            let ident = ident.to_string();
-                            let docs = lit_strings_to_string_quoted(docs);
-                            quote! {
-                                [::type_description::StructField::new(#ident, #docs, <#ty as ::type_description::AsTypeDescription>::as_type_description(), #optional)]
-                            }
-                        }
-                        TypeField::Flatten { ty } => {
-                            quote! {
-                                {
-                                    let desc = <#ty as ::type_description::AsTypeDescription>::as_type_description();
-                                    match desc.kind() {
-                                        ::type_description::TypeKind::Struct(fields) => fields.clone(),
-                                        _ => panic!("Tried to flatten a non-struct field")
-                                    }
-                                }
-                            }
-                        }
-                    }
-
-                });
-
+                let kind = struct_fields_quoted(fields);
                 quote! {
                     ::type_description::TypeDescription::new(
-                        ::std::string::String::from(#ident_name),
-                        ::type_description::TypeKind::Struct({
-                            let mut fields = vec![];
-                            #(
-                                fields.extend(#fields);
-                            )*
-                            fields
-                        }),
+                        type_name.clone(),
+                        #kind,
                         #outer_docs
                     )
                 }
             }
             TypeQuoteKind::Enum(kind, variants) => {
                 let kind = match kind {
+                    TypeEnumKind::External => {
+                        quote! {
+                            ::type_description::TypeEnumKind::External
+                        }
+                    }
                     TypeEnumKind::Tagged(tag) => {
                         quote! {
                             ::type_description::TypeEnumKind::Tagged(::std::borrow::Cow::Borrowed(#tag))
                         }
                     }
+                    TypeEnumKind::Adjacent { tag, content } => {
+                        quote! {
+                            ::type_description::TypeEnumKind::Adjacent {
+                                tag: ::std::borrow::Cow::Borrowed(#tag),
+                                content: ::std::borrow::Cow::Borrowed(#content),
+                            }
+                        }
+                    }
                     TypeEnumKind::Untagged => {
                         quote! {
                             ::type_description::TypeEnumKind::Untagged
@@ -224,6 +915,7 @@ impl<'q> ToTokens for TypeQuote<'q> {
                 };
 
                 let variants = variants.iter().map(|var| {
+                    let name = &var.name;
                     let docs = lit_strings_to_string_quoted(&var.docs);
                     match &var.kind {
                         TypeVariantKind::Wrapped(ident, TypeField::Flatten { ty: _ }) => {
@@ -232,19 +924,19 @@ impl<'q> ToTokens for TypeQuote<'q> {
                                 "Cannot flatten wrapped fields"
                             )
                         }
-                        TypeVariantKind::Wrapped(ident, TypeField::Simple { ty, .. }) => {
+                        TypeVariantKind::Wrapped(ident, TypeField::Simple { ty, constraints, bytes, .. }) => {
                             // we ignore the above docs since the outer docs are the important ones
-                            // TODO: Emit an error if an inner type in a enum is annotated
                             let ident = ident.to_string();
+                            let desc = field_description_quoted(ty, bytes, constraints);
                             quote!{
                                 ::type_description::EnumVariant::new(
-                                    #ident,
+                                    #name,
                                     #docs,
                                     ::type_description::EnumVariantRepresentation::Wrapped(
                                         std::boxed::Box::new(::type_description::TypeDescription::new(
                                             ::std::string::String::from(#ident),
                                             ::type_description::TypeKind::Wrapped(
-                                                std::boxed::Box::new(<#ty as ::type_description::AsTypeDescription>::as_type_description())
+                                                std::boxed::Box::new(#desc)
                                             ),
                                             None,
                                         ))
@@ -252,58 +944,47 @@ impl<'q> ToTokens for TypeQuote<'q> {
                                 )
                             }
                         }
+                        TypeVariantKind::Tuple(ident, types) => {
+                            let kind = tuple_kind_quoted(types);
+                            let ident = ident.to_string();
+                            quote! {
+                                ::type_description::EnumVariant::new(
+                                    #name,
+                                    #docs,
+                                    ::type_description::EnumVariantRepresentation::Wrapped(
+                                        std::boxed::Box::new(::type_description::TypeDescription::new(
+                                            ::std::string::String::from(#ident),
+                                            #kind,
+                                            None,
+                                        ))
+                                    )
+                                )
+                            }
+                        }
                         TypeVariantKind::Struct(ident, fields) => {
-                            let fields = fields.iter().map(|field| {
-
-                                match field {
-                                    TypeField::Simple { ident, ty, docs, optional } =>  {
-                                        let ident = ident.to_string();
-                                        let docs = lit_strings_to_string_quoted(docs);
-                                        quote! {
-                                            ::type_description::StructField::new(
-                                                #ident,
-                                                #docs,
-                                                <#ty as ::type_description::AsTypeDescription>::as_type_description(),
-                                                #optional,
-                                            )
-                                        }
-                                    }
-                                    TypeField::Flatten { ty: _ } => {
-                                        quote! {}
-                                    }
-                                }
-
-                            });
-
+                            let kind = struct_fields_quoted(fields);
                             let ident = ident.to_string();
                             quote! {
                                 ::type_description::EnumVariant::new(
-                                    #ident,
+                                    #name,
                                     #docs,
                                     ::type_description::EnumVariantRepresentation::Wrapped(
                                         std::boxed::Box::new(::type_description::TypeDescription::new(
                                             ::std::string::String::from(#ident),
-                                            ::type_description::TypeKind::Struct({
-                                                let mut fields = vec![];
-                                                #(
-                                                    fields.push(#fields);
-                                                 )*
-                                                fields
-                                            }),
+                                            #kind,
                                             None
                                         ))
                                     )
                                 )
                             }
                         }
-                        TypeVariantKind::String(ident) => {
-                            let ident = ident.to_string();
+                        TypeVariantKind::String(_) => {
                             quote!{
                                 ::type_description::EnumVariant::new(
-                                    #ident,
+                                    #name,
                                     #docs,
                                     ::type_description::EnumVariantRepresentation::String(
-                                        ::std::borrow::Cow::Borrowed(#ident)
+                                        ::std::borrow::Cow::Borrowed(#name)
                                     )
                                 )
                             }
@@ -313,7 +994,7 @@ impl<'q> ToTokens for TypeQuote<'q> {
 
                 quote! {
                     ::type_description::TypeDescription::new(
-                        ::std::string::String::from(#ident_name),
+                        type_name.clone(),
                         ::type_description::TypeKind::Enum(
                             #kind,
                             vec![#(#variants),*]
@@ -333,100 +1014,58 @@ pub fn derive_type_description(input: TS) -> TS {
 
     let ident = &input.ident;
 
-    let desc_container_attributes = input
-        .attrs
-        .iter()
-        .filter(|attr| attr.path.is_ident("description"))
-        .collect::<Vec<_>>();
+    let container = parse_container_attributes(input.attrs.iter());
+    let use_serde = container.use_serde;
 
-    let use_serde = desc_container_attributes
-        .iter()
-        .filter(|attr| attr.path.is_ident("description"))
-        .any(|attr| match attr.parse_meta() {
-            Err(_) => false,
-            Ok(meta) => match meta {
-                syn::Meta::List(kind) => {
-                    if kind.nested.len() != 1 {
-                        return false;
-                    }
+    let serde_container = if use_serde {
+        extra_serde_container_attributes(input.attrs.iter())
+    } else {
+        vec![]
+    };
+    let serde_rename_all = serde_container.iter().find_map(|attr| match attr {
+        SerdeContainerAttribute::RenameAll(rule) => Some(*rule),
+        _ => None,
+    });
+    let serde_rename_all_fields = serde_container.iter().find_map(|attr| match attr {
+        SerdeContainerAttribute::RenameAllFields(rule) => Some(*rule),
+        _ => None,
+    });
+    let serde_container_default = serde_container.iter().find_map(|attr| match attr {
+        SerdeContainerAttribute::HasDefault => {
+            Some(quote!(<Self as ::std::default::Default>::default()))
+        }
+        SerdeContainerAttribute::DefaultWith(path) => Some(quote!(#path())),
+        _ => None,
+    });
 
-                    match kind.nested.first() {
-                        Some(NestedMeta::Meta(Meta::Path(path))) => path.is_ident("use_serde"),
-                        _ => false,
-                    }
-                }
-                _ => false,
-            },
-        });
+    let type_params = input
+        .generics
+        .type_params()
+        .map(|param| &param.ident)
+        .collect::<Vec<_>>();
 
     let type_desc_kind: TypeQuoteKind = match &input.data {
         syn::Data::Struct(data) => match &data.fields {
-            syn::Fields::Named(fields) => TypeQuoteKind::Struct(
-                fields
-                    .named
-                    .iter()
-                    .map(|f| {
-                        (
-                            f,
-                            TypeField::Simple {
-                                ident: f.ident.as_ref().cloned().unwrap(),
-                                ty: &f.ty,
-                                docs: extract_docs_from_attributes(f.attrs.iter()),
-                                optional: false,
-                            },
-                        )
-                    })
-                    .flat_map(|(field, mut type_field)| {
-                        if use_serde {
-                            let serde_field_attrs =
-                                extra_serde_field_attributes(field.attrs.iter());
-
-                            if let Some(serde_field_attrs) = serde_field_attrs {
-                                if serde_field_attrs
-                                    .iter()
-                                    .any(|s| s == &SerdeFieldAttribute::Flatten)
-                                {
-                                    type_field = TypeField::Flatten { ty: &field.ty };
-                                }
-
-                                if let TypeField::Simple {
-                                    ident,
-                                    ty: _,
-                                    docs: _,
-                                    optional,
-                                } = &mut type_field
-                                {
-                                    for attr in serde_field_attrs {
-                                        match attr {
-                                            SerdeFieldAttribute::Rename(litstr) => {
-                                                *ident = Ident::new(&litstr.value(), litstr.span());
-                                            }
-                                            SerdeFieldAttribute::HasDefault => {
-                                                *optional = true;
-                                            }
-                                            SerdeFieldAttribute::Skip => {
-                                                return None;
-                                            }
-                                            _ => (),
-                                        }
-                                    }
-                                }
-                            }
-                            Some(type_field)
-                        } else {
-                            Some(type_field)
-                        }
-                    })
-                    .collect(),
-            ),
+            syn::Fields::Named(fields) => TypeQuoteKind::Struct(named_fields(
+                fields,
+                &FieldOptions {
+                    use_serde,
+                    rename_rule: serde_rename_all,
+                    container_default: serde_container_default,
+                    type_params: &type_params,
+                },
+            )),
             syn::Fields::Unnamed(fields) => {
-                if fields.unnamed.len() != 1 {
-                    abort!(
-                        fields,
-                        "Tuple structs should only contain a single variant."
-                    )
+                if fields.unnamed.len() == 1 {
+                    TypeQuoteKind::Wrapped(wrapped_field(
+                        fields.unnamed.first().unwrap(),
+                        ident.to_string(),
+                        None,
+                        use_serde,
+                    ))
+                } else {
+                    TypeQuoteKind::Tuple(fields.unnamed.iter().map(|f| &f.ty).collect())
                 }
-                TypeQuoteKind::Wrapped(&fields.unnamed.first().unwrap().ty)
             }
             syn::Fields::Unit => abort!(
                 ident,
@@ -435,102 +1074,106 @@ pub fn derive_type_description(input: TS) -> TS {
         },
         syn::Data::Enum(data) => {
             let enum_kind: TypeEnumKind = {
-                let error_no_kind = || abort!(ident, "Enums need to specify what kind of tagging they use"; help = "Use #[description(untagged)] for untagged enums, and #[description(tag = \"type\")] for internally tagged variants. Other kinds are not supported.");
-
-                if desc_container_attributes.is_empty() {
-                    error_no_kind()
-                }
-
-                let mut found_enum_kind = None;
+                let (mut tag, mut content, mut untagged) =
+                    (container.tag, container.content, container.untagged);
 
-                for potential_kind in &desc_container_attributes {
-                    match potential_kind
-                        .parse_meta()
-                        .expect_or_abort("Could not parse #[description] meta attribute.")
-                    {
-                        syn::Meta::List(kind) => {
-                            if kind.nested.len() != 1 {
-                                continue;
-                            }
-
-                            match kind.nested.first() {
-                                Some(NestedMeta::Meta(Meta::NameValue(MetaNameValue {
-                                    path,
-                                    lit: Lit::Str(lit_str),
-                                    ..
-                                }))) => {
-                                    if path.is_ident("tag") {
-                                        found_enum_kind =
-                                            Some(TypeEnumKind::Tagged(lit_str.clone()));
-                                    }
-                                }
-                                Some(NestedMeta::Meta(Meta::Path(path))) => {
-                                    if path.is_ident("untagged") {
-                                        found_enum_kind = Some(TypeEnumKind::Untagged);
-                                    }
-                                }
-                                _ => {
-                                    continue;
-                                }
+                if tag.is_none() && content.is_none() && !untagged {
+                    for attr in &serde_container {
+                        match attr {
+                            SerdeContainerAttribute::Tag(litstr) => tag = Some(litstr.clone()),
+                            SerdeContainerAttribute::Content(litstr) => {
+                                content = Some(litstr.clone())
                             }
+                            SerdeContainerAttribute::Untagged => untagged = true,
+                            _ => (),
                         }
-                        _ => continue,
                     }
                 }
 
-                if found_enum_kind.is_none() {
-                    error_no_kind()
+                match (tag, content, untagged) {
+                    (None, None, false) => TypeEnumKind::External,
+                    (None, None, true) => TypeEnumKind::Untagged,
+                    (Some(tag), None, false) => TypeEnumKind::Tagged(tag),
+                    (Some(tag), Some(content), false) => TypeEnumKind::Adjacent { tag, content },
+                    (None, Some(content), _) => {
+                        abort!(content, "Enums with a content field also need a tag field"; help = "Use #[description(tag = \"t\", content = \"c\")] for adjacently tagged enums.")
+                    }
+                    (Some(tag), _, true) => abort!(tag, "Untagged enums cannot have a tag field"),
                 }
-
-                found_enum_kind.unwrap()
             };
 
             let variants = data
                 .variants
                 .iter()
-                .map(|var| {
+                .filter_map(|var| {
+                    let serde_variant_attrs = if use_serde {
+                        extra_serde_field_attributes(var.attrs.iter()).unwrap_or_default()
+                    } else {
+                        vec![]
+                    };
+
+                    if serde_variant_attrs.contains(&SerdeFieldAttribute::Skip) {
+                        return None;
+                    }
+
+                    let name = serde_variant_attrs
+                        .iter()
+                        .find_map(|attr| match attr {
+                            SerdeFieldAttribute::Rename(litstr) => Some(litstr.value()),
+                            _ => None,
+                        })
+                        .unwrap_or_else(|| {
+                            let name = var.ident.unraw().to_string();
+                            match serde_rename_all {
+                                Some(rule) => rule.apply_to_variant(&name),
+                                None => name,
+                            }
+                        });
+
+                    let fields_rename_rule = serde_variant_attrs
+                        .iter()
+                        .find_map(|attr| match attr {
+                            SerdeFieldAttribute::RenameAll(rule) => Some(*rule),
+                            _ => None,
+                        })
+                        .or(serde_rename_all_fields);
+
                     let kind = match &var.fields {
                         syn::Fields::Named(fields) => TypeVariantKind::Struct(
                             &var.ident,
-                            fields
-                                .named
-                                .iter()
-                                .map(|f| TypeField::Simple {
-                                    ident: f.ident.as_ref().cloned().unwrap(),
-                                    ty: &f.ty,
-                                    docs: extract_docs_from_attributes(f.attrs.iter()),
-                                    optional: false,
-                                })
-                                .collect(),
+                            named_fields(
+                                fields,
+                                &FieldOptions {
+                                    use_serde,
+                                    rename_rule: fields_rename_rule,
+                                    container_default: None,
+                                    type_params: &type_params,
+                                },
+                            ),
                         ),
-                        syn::Fields::Unnamed(fields) => {
-                            if fields.unnamed.len() != 1 {
-                                abort!(
-                                    fields,
-                                    "Tuple structs should only contain a single variant."
-                                )
-                            }
-                            TypeVariantKind::Wrapped(
+                        syn::Fields::Unnamed(fields) if fields.unnamed.len() != 1 => {
+                            TypeVariantKind::Tuple(
                                 &var.ident,
-                                TypeField::Simple {
-                                    ident: var.ident.clone(),
-                                    ty: &fields.unnamed.first().unwrap().ty,
-                                    docs: extract_docs_from_attributes(var.attrs.iter()),
-                                    optional: false,
-                                },
+                                fields.unnamed.iter().map(|f| &f.ty).collect(),
                             )
                         }
+                        syn::Fields::Unnamed(fields) => TypeVariantKind::Wrapped(
+                            &var.ident,
+                            wrapped_field(
+                                fields.unnamed.first().unwrap(),
+                                var.ident.to_string(),
+                                extract_docs_from_attributes(var.attrs.iter()),
+                                use_serde,
+                            ),
+                        ),
                         syn::Fields::Unit => TypeVariantKind::String(&var.ident),
                     };
                     let docs = extract_docs_from_attributes(var.attrs.iter());
-                    Some(TypeVariant { kind, docs })
+                    Some(TypeVariant { name, kind, docs })
                 })
-                .collect::<Option<_>>();
+                .collect();
 
-            TypeQuoteKind::Enum(
-                enum_kind,
-                variants.expect_or_abort("Enum contains invalid variants"),
-            )
+            TypeQuoteKind::Enum(enum_kind, variants)
         }
         syn::Data::Union(_) => {
             abort!(
@@ -542,16 +1185,59 @@ pub fn derive_type_description(input: TS) -> TS {
 
     let docs = extract_docs_from_attributes(input.attrs.iter());
 
-    let type_desc = TypeQuote {
-        kind: type_desc_kind,
-        docs,
-        ident,
+    let type_desc = container.constraints.apply(
+        TypeQuote {
+            kind: type_desc_kind,
+            docs,
+        }
+        .into_token_stream(),
+    );
+
+    let mut generics = input.generics.clone();
+
+    // Parameters left out of a custom bound can only be named by their Rust type name
+    let type_param_names = type_params
+        .iter()
+        .map(|param| match &container.bound {
+            Some(bound) if !bound_describes(bound, param) => {
+                quote!(::std::any::type_name::<#param>())
+            }
+            _ => quote!(<#param as ::type_description::AsTypeDescription>::as_type_description().name()),
+        })
+        .collect::<Vec<_>>();
+
+    {
+        let where_clause = generics.make_where_clause();
+        match container.bound {
+            Some(bound) => where_clause.predicates.extend(bound),
+            None => where_clause
+                .predicates
+                .extend(type_params.iter().map::<WherePredicate, _>(
+                    |param| parse_quote!(#param: ::type_description::AsTypeDescription),
+                )),
+        }
+    }
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let ident_name = ident.to_string();
+    let type_name = if type_params.is_empty() {
+        quote!(::std::string::String::from(#ident_name))
+    } else {
+        let format_string = format!("{ident_name}<{}>", vec!["{}"; type_params.len()].join(", "));
+        quote! {
+            ::std::format!(
+                #format_string,
+                #(#type_param_names),*
+            )
+        }
     };
 
     let expanded = quote! {
-        impl ::type_description::AsTypeDescription for #ident {
+        impl #impl_generics ::type_description::AsTypeDescription for #ident #ty_generics #where_clause {
             fn as_type_description() -> ::type_description::TypeDescription {
-                #type_desc
+                let type_name = #type_name;
+                ::type_description::describe_recursive::<Self>(&type_name, || #type_desc)
             }
         }
     };